@@ -23,6 +23,109 @@ struct Args {
     )]
     enable_const_enums: bool,
 
+    /// Enable generic constraints
+    #[clap(
+        long = "generic-constraints",
+        help = "Translate trait bounds like `T: Into<UserId>` to typescript constraints like `T extends UserId`"
+    )]
+    enable_generic_constraints: bool,
+
+    /// Enable null optionals
+    #[clap(
+        long = "null-optionals",
+        help = "Write `Option<T>` as `T | null` (how serde serializes `None`) instead of optional fields and `T | undefined`"
+    )]
+    enable_null_optionals: bool,
+
+    /// Features to evaluate `cfg` attributes with
+    #[clap(
+        long,
+        value_delimiter = ',',
+        help = "Only export the items and fields which exist with these features enabled, by evaluating `#[cfg(...)]` attributes (see also --cfg-test and --target-os)"
+    )]
+    features: Option<Vec<String>>,
+
+    /// Set `test` when evaluating `cfg` attributes
+    #[clap(
+        long = "cfg-test",
+        help = "Evaluate `#[cfg(...)]` attributes with `test` set"
+    )]
+    cfg_test: bool,
+
+    /// Target OS to evaluate `cfg` attributes with
+    #[clap(
+        long = "target-os",
+        help = "Evaluate `#[cfg(...)]` attributes for this `target_os` (defaults to the current OS)"
+    )]
+    target_os: Option<String>,
+
+    /// Read crates from their root
+    #[clap(
+        long = "crate-root",
+        help = "Treat the inputs as crate roots (`lib.rs`/`main.rs`, or a directory containing them) and only read the modules they declare"
+    )]
+    enable_crate_root: bool,
+
+    /// Export referenced types
+    #[clap(
+        long = "include-referenced",
+        help = "Also export the types referenced by exported types (in fields, variants and generic arguments) when they're defined in the input"
+    )]
+    enable_referenced_items: bool,
+
+    /// Export serde types
+    #[clap(
+        long = "serde-types",
+        help = "Also export the structs and enums deriving `Serialize` or `Deserialize`, without #[tsync]"
+    )]
+    enable_serde_types: bool,
+
+    /// Only export public serde types
+    #[clap(
+        long = "pub-only",
+        requires = "enable_serde_types",
+        help = "With --serde-types, only export the types declared `pub`"
+    )]
+    pub_only: bool,
+
+    /// Path globs of the serde types to export
+    #[clap(
+        long = "serde-path",
+        requires = "enable_serde_types",
+        help = "With --serde-types, only export the types whose path matches one of these globs, like `crate::models::*` or `crate::api::**`"
+    )]
+    serde_paths: Vec<String>,
+
+    /// Wrapper types to unwrap in function signatures
+    #[clap(
+        long = "unwrap-type",
+        help = "Replace this wrapper type by the type it wraps in the arguments and return types of #[tsync] functions, in addition to `Result` and `Json` (e.g. --unwrap-type Data)"
+    )]
+    unwrapped_types: Vec<String>,
+
+    /// Generate Tauri command wrappers
+    #[clap(
+        long = "tauri",
+        help = "Write #[tsync] functions as typed wrappers calling the Tauri command of the same name with `invoke` (only in .ts files)"
+    )]
+    enable_tauri_commands: bool,
+
+    /// Generate wrappers for all Tauri commands
+    #[clap(
+        long = "tauri-commands",
+        requires = "enable_tauri_commands",
+        help = "With --tauri, also write wrappers for the #[tauri::command] functions which aren't marked with #[tsync]"
+    )]
+    enable_tauri_command_attribute: bool,
+
+    /// Module to import `invoke` from
+    #[clap(
+        long = "tauri-invoke-module",
+        requires = "enable_tauri_commands",
+        help = "With --tauri, import `invoke` from this module instead of `@tauri-apps/api/core`, e.g. `@tauri-apps/api/tauri` for Tauri v1"
+    )]
+    tauri_invoke_module: Option<String>,
+
     // TODO: add "create-module" functionality (so generated types can be under a specified namespace like Rust.MyType)
     // useModules: bool,
 
@@ -56,5 +159,40 @@ struct Args {
 fn main() {
     let args: Args = Args::parse();
 
-    tsync::generate_typescript_defs(args.input, args.output, args.debug, args.enable_const_enums);
+    // `cfg` attributes are only evaluated when a configuration is given
+    let cfg = if args.features.is_some() || args.cfg_test || args.target_os.is_some() {
+        let default = tsync::CfgSet::default();
+        Some(tsync::CfgSet {
+            features: args
+                .features
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|feature| !feature.is_empty())
+                .collect(),
+            test: args.cfg_test,
+            target_os: args.target_os.unwrap_or(default.target_os),
+        })
+    } else {
+        None
+    };
+
+    let settings = tsync::BuildSettings {
+        enable_const_enums: args.enable_const_enums,
+        enable_generic_constraints: args.enable_generic_constraints,
+        enable_null_optionals: args.enable_null_optionals,
+        cfg,
+        enable_crate_root: args.enable_crate_root,
+        enable_referenced_items: args.enable_referenced_items,
+        serde_types: args.enable_serde_types.then_some(tsync::SerdeTypes {
+            pub_only: args.pub_only,
+            paths: args.serde_paths,
+        }),
+        unwrapped_types: args.unwrapped_types,
+        enable_tauri_commands: args.enable_tauri_commands,
+        enable_tauri_command_attribute: args.enable_tauri_command_attribute,
+        tauri_invoke_module: args.tauri_invoke_module,
+        ..Default::default()
+    };
+
+    tsync::generate_typescript_defs_with_settings(args.input, args.output, args.debug, settings);
 }
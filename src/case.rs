@@ -0,0 +1,205 @@
+//! Case conversion of variant and field names, following the rules serde applies for
+//! `#[serde(rename_all = "...")]` exactly. Serde doesn't split names into words like
+//! most case conversion crates do, e.g. a `HTTPServer` variant becomes `h_t_t_p_server`
+//! in snake case, and `field_1` becomes `field1` in camel case.
+
+/// A `rename_all` rule, see https://serde.rs/container-attrs.html#rename_all
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum RenameRule {
+    /// Rename direct children to "lowercase" style.
+    LowerCase,
+    /// Rename direct children to "UPPERCASE" style.
+    UpperCase,
+    /// Rename direct children to "PascalCase" style, as typically used for
+    /// enum variants.
+    PascalCase,
+    /// Rename direct children to "camelCase" style.
+    CamelCase,
+    /// Rename direct children to "snake_case" style, as commonly used for
+    /// fields.
+    SnakeCase,
+    /// Rename direct children to "SCREAMING_SNAKE_CASE" style, as commonly
+    /// used for constants.
+    ScreamingSnakeCase,
+    /// Rename direct children to "kebab-case" style.
+    KebabCase,
+    /// Rename direct children to "SCREAMING-KEBAB-CASE" style.
+    ScreamingKebabCase,
+}
+
+static RENAME_RULES: &[(&str, RenameRule)] = &[
+    ("lowercase", RenameRule::LowerCase),
+    ("UPPERCASE", RenameRule::UpperCase),
+    ("PascalCase", RenameRule::PascalCase),
+    ("camelCase", RenameRule::CamelCase),
+    ("snake_case", RenameRule::SnakeCase),
+    ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnakeCase),
+    ("kebab-case", RenameRule::KebabCase),
+    ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebabCase),
+];
+
+impl RenameRule {
+    /// Parse the value of a `rename_all` attribute, like `"camelCase"`
+    pub fn from_name(rule: &str) -> Option<Self> {
+        RENAME_RULES
+            .iter()
+            .find(|(name, _)| *name == rule)
+            .map(|(_, rule)| *rule)
+    }
+
+    /// Apply the rule to a variant name, which is assumed to be written in PascalCase
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::PascalCase => variant.to_owned(),
+            RenameRule::LowerCase => variant.to_ascii_lowercase(),
+            RenameRule::UpperCase => variant.to_ascii_uppercase(),
+            RenameRule::CamelCase => lowercase_first(variant),
+            RenameRule::SnakeCase => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnakeCase => RenameRule::SnakeCase
+                .apply_to_variant(variant)
+                .to_ascii_uppercase(),
+            RenameRule::KebabCase => RenameRule::SnakeCase
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+            RenameRule::ScreamingKebabCase => RenameRule::ScreamingSnakeCase
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule to a field name, which is assumed to be written in snake_case
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::LowerCase | RenameRule::SnakeCase => field.to_owned(),
+            RenameRule::UpperCase | RenameRule::ScreamingSnakeCase => field.to_ascii_uppercase(),
+            RenameRule::PascalCase => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            RenameRule::CamelCase => lowercase_first(&RenameRule::PascalCase.apply_to_field(field)),
+            RenameRule::KebabCase => field.replace('_', "-"),
+            RenameRule::ScreamingKebabCase => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+/// Lowercase the first character of a name, which may not be ASCII
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenameRule;
+    use serde::Serialize;
+
+    /// Names which are converted differently by serde and by crates splitting words
+    static VARIANTS: &[&str] = &["Simple", "HTTPServer", "Version2", "IOError", "A"];
+    static FIELDS: &[&str] = &["simple", "field_1", "http_server", "a_b_c", "x"];
+
+    macro_rules! serialized_names {
+        ($rule:literal) => {{
+            #[derive(Serialize)]
+            #[serde(rename_all = $rule)]
+            #[allow(clippy::upper_case_acronyms)]
+            enum Variants {
+                Simple,
+                HTTPServer,
+                Version2,
+                IOError,
+                A,
+            }
+
+            #[derive(Serialize, Default)]
+            #[serde(rename_all = $rule)]
+            struct Fields {
+                simple: u8,
+                field_1: u8,
+                http_server: u8,
+                a_b_c: u8,
+                x: u8,
+            }
+
+            let variants = [
+                Variants::Simple,
+                Variants::HTTPServer,
+                Variants::Version2,
+                Variants::IOError,
+                Variants::A,
+            ]
+            .iter()
+            .map(|variant| serde_json::to_value(variant).unwrap())
+            .map(|value| value.as_str().unwrap().to_owned())
+            .collect::<Vec<_>>();
+
+            // keys are kept in declaration order by serializing into a string
+            let fields = serde_json::to_string(&Fields::default()).unwrap();
+            let fields = fields
+                .trim_matches(|c| c == '{' || c == '}')
+                .split(',')
+                .map(|entry| entry.split('"').nth(1).unwrap().to_owned())
+                .collect::<Vec<_>>();
+
+            (RenameRule::from_name($rule).unwrap(), variants, fields)
+        }};
+    }
+
+    fn check((rule, variants, fields): (RenameRule, Vec<String>, Vec<String>)) {
+        for (name, expected) in VARIANTS.iter().zip(variants) {
+            assert_eq!(
+                rule.apply_to_variant(name),
+                expected,
+                "{rule:?} variant {name}"
+            );
+        }
+        for (name, expected) in FIELDS.iter().zip(fields) {
+            assert_eq!(rule.apply_to_field(name), expected, "{rule:?} field {name}");
+        }
+    }
+
+    #[test]
+    fn matches_serde() {
+        check(serialized_names!("lowercase"));
+        check(serialized_names!("UPPERCASE"));
+        check(serialized_names!("PascalCase"));
+        check(serialized_names!("camelCase"));
+        check(serialized_names!("snake_case"));
+        check(serialized_names!("SCREAMING_SNAKE_CASE"));
+        check(serialized_names!("kebab-case"));
+        check(serialized_names!("SCREAMING-KEBAB-CASE"));
+    }
+
+    #[test]
+    fn non_ascii_names() {
+        assert_eq!(RenameRule::CamelCase.apply_to_variant("Élan"), "élan");
+        assert_eq!(
+            RenameRule::CamelCase.apply_to_field("été_chaud"),
+            "étéChaud"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+//! Conditional compilation: the attributes wrapped in `#[cfg_attr(predicate, ...)]` are
+//! unwrapped before any item is looked at, so that gating `#[tsync]` or `#[serde(...)]`
+//! behind a feature (e.g. `#[cfg_attr(feature = "ts", tsync)]`) works like writing them
+//! directly. When a [`CfgSet`] is given, the predicates of `cfg` and `cfg_attr` attributes
+//! are evaluated against it, and the items, fields and variants which don't exist in that
+//! configuration are removed.
+
+use syn::punctuated::Punctuated;
+use syn::Token;
+
+/// The configuration `#[cfg(...)]` predicates are evaluated against. Options other than
+/// the ones below (like `debug_assertions` or `target_arch`) are considered unset.
+#[derive(Clone, Debug)]
+pub struct CfgSet {
+    /// enabled features, for `feature = "..."`
+    pub features: Vec<String>,
+    /// whether `test` is set, which it isn't by default
+    pub test: bool,
+    /// the value of `target_os`, which also determines `unix`, `windows` and
+    /// `target_family`. Defaults to the OS tsync runs on.
+    pub target_os: String,
+}
+
+impl Default for CfgSet {
+    fn default() -> Self {
+        CfgSet {
+            features: Vec::new(),
+            test: false,
+            target_os: std::env::consts::OS.to_owned(),
+        }
+    }
+}
+
+static UNIX_OSES: &[&str] = &[
+    "linux",
+    "macos",
+    "ios",
+    "tvos",
+    "watchos",
+    "visionos",
+    "android",
+    "freebsd",
+    "netbsd",
+    "openbsd",
+    "dragonfly",
+    "solaris",
+    "illumos",
+    "haiku",
+    "redox",
+    "aix",
+    "hurd",
+    "nto",
+];
+
+impl CfgSet {
+    fn target_family(&self) -> Option<&'static str> {
+        if UNIX_OSES.contains(&self.target_os.as_str()) {
+            Some("unix")
+        } else if self.target_os == "windows" {
+            Some("windows")
+        } else {
+            None
+        }
+    }
+
+    /// Evaluate a predicate like `all(feature = "ts", not(test))`. Malformed predicates
+    /// are considered true, so nothing is removed because of them.
+    fn eval(&self, predicate: &syn::Meta) -> bool {
+        match predicate {
+            syn::Meta::Path(path) => match path.get_ident() {
+                Some(ident) if ident == "test" => self.test,
+                Some(ident) if ident == "unix" || ident == "windows" => {
+                    self.target_family() == Some(ident.to_string().as_str())
+                }
+                _ => false,
+            },
+            syn::Meta::NameValue(name_value) => {
+                let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                }) = &name_value.value
+                else {
+                    return true;
+                };
+                let value = value.value();
+                match name_value.path.get_ident() {
+                    Some(ident) if ident == "feature" => self.features.contains(&value),
+                    Some(ident) if ident == "target_os" => self.target_os == value,
+                    Some(ident) if ident == "target_family" => {
+                        self.target_family() == Some(value.as_str())
+                    }
+                    _ => false,
+                }
+            }
+            syn::Meta::List(list) => {
+                let Ok(predicates) =
+                    list.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+                else {
+                    return true;
+                };
+                match list.path.get_ident() {
+                    Some(ident) if ident == "all" => predicates.iter().all(|p| self.eval(p)),
+                    Some(ident) if ident == "any" => predicates.iter().any(|p| self.eval(p)),
+                    Some(ident) if ident == "not" && predicates.len() == 1 => {
+                        !self.eval(&predicates[0])
+                    }
+                    _ => true,
+                }
+            }
+        }
+    }
+}
+
+/// Unwrap the `cfg_attr` attributes of the items of a file (including the ones of inline
+/// modules), their fields and variants, and remove the ones which don't exist in the
+/// given configuration
+pub(crate) fn apply_cfg(items: &mut Vec<syn::Item>, cfg: Option<&CfgSet>) {
+    items.retain_mut(|item| {
+        let attrs = match item {
+            syn::Item::Struct(item) => {
+                expand_fields(&mut item.fields, cfg);
+                &mut item.attrs
+            }
+            syn::Item::Enum(item) => {
+                item.variants = std::mem::take(&mut item.variants)
+                    .into_iter()
+                    .filter_map(|mut variant| {
+                        expand_fields(&mut variant.fields, cfg);
+                        is_enabled(&mut variant.attrs, cfg).then_some(variant)
+                    })
+                    .collect();
+                &mut item.attrs
+            }
+            syn::Item::Type(item) => &mut item.attrs,
+            syn::Item::Const(item) => &mut item.attrs,
+            syn::Item::Fn(item) => &mut item.attrs,
+            syn::Item::Use(item) => &mut item.attrs,
+            syn::Item::Mod(item) => {
+                if let Some((_, items)) = &mut item.content {
+                    apply_cfg(items, cfg);
+                }
+                &mut item.attrs
+            }
+            _ => return true,
+        };
+        is_enabled(attrs, cfg)
+    });
+}
+
+fn expand_fields(fields: &mut syn::Fields, cfg: Option<&CfgSet>) {
+    let filter = |fields: &mut Punctuated<syn::Field, Token![,]>| {
+        *fields = std::mem::take(fields)
+            .into_iter()
+            .filter_map(|mut field| is_enabled(&mut field.attrs, cfg).then_some(field))
+            .collect();
+    };
+    match fields {
+        syn::Fields::Named(fields) => filter(&mut fields.named),
+        syn::Fields::Unnamed(fields) => filter(&mut fields.unnamed),
+        syn::Fields::Unit => {}
+    }
+}
+
+/// Expand the `cfg_attr` attributes of an item, field or variant, and check whether
+/// its `cfg` attributes hold
+fn is_enabled(attrs: &mut Vec<syn::Attribute>, cfg: Option<&CfgSet>) -> bool {
+    expand_attrs(attrs, cfg);
+
+    let Some(cfg) = cfg else {
+        return true;
+    };
+
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .all(|attr| match attr.parse_args::<syn::Meta>() {
+            Ok(predicate) => cfg.eval(&predicate),
+            Err(_) => true,
+        })
+}
+
+/// Replace each `#[cfg_attr(predicate, a, b)]` whose predicate holds with `#[a]` and
+/// `#[b]`, including nested ones like `#[cfg_attr(unix, cfg_attr(feature = "ts", tsync))]`.
+/// Without a configuration, all of them are unwrapped.
+fn expand_attrs(attrs: &mut Vec<syn::Attribute>, cfg: Option<&CfgSet>) {
+    if !attrs.iter().any(is_cfg_attr) {
+        return;
+    }
+
+    let mut expanded = Vec::with_capacity(attrs.len());
+    for attr in std::mem::take(attrs) {
+        match get_conditional_attrs(&attr) {
+            Some((predicate, metas)) => {
+                if cfg.is_some_and(|cfg| !cfg.eval(&predicate)) {
+                    continue;
+                }
+                let mut unwrapped = metas
+                    .into_iter()
+                    .map(|meta| syn::Attribute {
+                        meta,
+                        ..attr.clone()
+                    })
+                    .collect();
+                expand_attrs(&mut unwrapped, cfg);
+                expanded.extend(unwrapped);
+            }
+            None => expanded.push(attr),
+        }
+    }
+    *attrs = expanded;
+}
+
+fn is_cfg_attr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("cfg_attr")
+}
+
+/// The predicate and attributes of a `cfg_attr`, or None if it's another or a malformed attribute
+fn get_conditional_attrs(attr: &syn::Attribute) -> Option<(syn::Meta, Vec<syn::Meta>)> {
+    if !is_cfg_attr(attr) {
+        return None;
+    }
+
+    let syn::Meta::List(list) = &attr.meta else {
+        return None;
+    };
+
+    let mut metas = list
+        .parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+        .ok()?
+        .into_iter();
+
+    let predicate = metas.next()?;
+    Some((predicate, metas.collect()))
+}
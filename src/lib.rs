@@ -1,31 +1,45 @@
+mod case;
+mod cfg;
+mod references;
+mod selection;
 mod to_typescript;
 mod typescript;
 pub mod utils;
 
 use state::InitCell;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use syn::ext::IdentExt;
 use walkdir::{DirEntry, WalkDir};
 
+pub use crate::cfg::CfgSet;
+pub use crate::selection::SerdeTypes;
+
 /// the #[tsync] attribute macro which marks structs and types to be translated into the final typescript definitions file
 pub use tsync_macro::tsync;
 
 use crate::to_typescript::ToTypescript;
+use convert_case::{Case, Casing};
 
 pub(crate) static DEBUG: InitCell<bool> = InitCell::new();
 
 /// macro to check from an syn::Item most of them have ident attribs
 /// that is the one we want to print but not sure!
 macro_rules! check_tsync {
-    ($x: ident, in: $y: tt, $z: tt) => {
-        let has_tsync_attribute = has_tsync_attribute(&$x.attrs);
+    ($x: ident, in: $y: tt, $state: ident, $z: tt) => {
+        check_tsync!($x, ident: $x.ident, in: $y, $state, false, $z)
+    };
+    ($x: ident, ident: $ident: expr, in: $y: tt, $state: ident, $selected: expr, $z: tt) => {
+        let has_tsync_attribute =
+            has_tsync_attribute(&$x.attrs) || is_exported(&$ident, $state) || $selected;
         if *DEBUG.get() {
             if has_tsync_attribute {
-                println!("Encountered #[tsync] {}: {}", $y, $x.ident.to_string());
+                println!("Encountered #[tsync] {}: {}", $y, $ident.to_string());
             } else {
-                println!("Encountered non-tsync {}: {}", $y, $x.ident.to_string());
+                println!("Encountered non-tsync {}: {}", $y, $ident.to_string());
             }
         }
 
@@ -39,6 +53,35 @@ macro_rules! check_tsync {
 pub struct BuildState /*<'a>*/ {
     pub types: String,
     pub unprocessed_files: Vec<PathBuf>,
+    /// set when a converted type refers to `JsonValue`, so its definition gets written once
+    pub uses_json_value: bool,
+    /// set when a Tauri command wrapper is written, so `invoke` gets imported once
+    pub uses_tauri_invoke: bool,
+    /// names brought into scope by `use` items of the file being processed, mapped to their full path
+    pub imports: HashMap<String, Vec<String>>,
+    /// module path of the file being processed, starting with `crate`
+    pub module_path: Vec<String>,
+    /// names of the generic type parameters of the item being processed
+    pub type_params: Vec<String>,
+    /// full paths of all the structs, enums and type aliases found in the input
+    pub declared_items: HashSet<Vec<String>>,
+    /// full paths of all the #[tsync] items found in the input, with the name they're given in typescript
+    pub tsync_items: Vec<(Vec<String>, String)>,
+    /// full paths of all the type aliases and newtypes of an `Option`, so their optionality carries over to fields
+    pub optional_aliases: Vec<Vec<String>>,
+    /// whether optional types are written as a union with `null` rather than `undefined`
+    pub null_optionals: bool,
+    /// full paths of the structs, enums and type aliases which are known not to be serialized as objects
+    pub non_object_items: Vec<Vec<String>>,
+    /// full paths of the unit structs and aliases of `()`, which serde serializes as null
+    pub unit_items: Vec<Vec<String>>,
+    /// problems found while converting items, which are reported once everything's processed
+    pub diagnostics: Vec<String>,
+    /// the configuration `#[cfg(...)]` attributes are evaluated against, if any
+    pub cfg: Option<CfgSet>,
+    /// full paths of the items exported because an exported item references them,
+    /// along with the full path of the item referencing them
+    pub referenced_items: Vec<(Vec<String>, Vec<String>)>,
     // pub ignore_file_config: Option<gitignore::File<'a>>,
 }
 
@@ -47,6 +90,36 @@ pub struct BuildState /*<'a>*/ {
 pub struct BuildSettings {
     pub uses_type_interface: bool,
     pub enable_const_enums: bool,
+    /// translate trait bounds of generic parameters to `extends` constraints where
+    /// possible, e.g. `T: Into<UserId>` becomes `T extends UserId`
+    pub enable_generic_constraints: bool,
+    /// write `Option<T>` as `T | null`, which is how serde serializes `None`, instead of
+    /// optional fields (`field?: T`) and `T | undefined`
+    pub enable_null_optionals: bool,
+    /// only export the items, fields and variants which exist in this configuration,
+    /// by evaluating their `#[cfg(...)]` and `#[cfg_attr(...)]` attributes. Without it,
+    /// `cfg` attributes are ignored and all `cfg_attr` attributes apply.
+    pub cfg: Option<CfgSet>,
+    /// treat the inputs as crate roots (`lib.rs` or `main.rs`, or a directory containing
+    /// them) and only read the files of the modules they declare with `mod foo;`
+    pub enable_crate_root: bool,
+    /// also export the items defined in the input which exported items reference
+    /// (in fields, variants and generic arguments), instead of marking each of them
+    pub enable_referenced_items: bool,
+    /// also export the items deriving `Serialize` or `Deserialize`, without marking them
+    pub serde_types: Option<SerdeTypes>,
+    /// wrapper types replaced by the type they wrap in the arguments and return types of
+    /// #[tsync] functions, like `Data` in `Data<User>`, in addition to `Result` and `Json`
+    pub unwrapped_types: Vec<String>,
+    /// write #[tsync] functions as wrappers calling the Tauri command of the same name
+    /// with `invoke`, when generating a `.ts` file
+    pub enable_tauri_commands: bool,
+    /// with `enable_tauri_commands`, also write wrappers for the `#[tauri::command]`
+    /// functions which aren't marked with #[tsync]
+    pub enable_tauri_command_attribute: bool,
+    /// the module `invoke` is imported from, `@tauri-apps/api/core` (Tauri v2) by default,
+    /// e.g. `@tauri-apps/api/tauri` for Tauri v1
+    pub tauri_invoke_module: Option<String>,
 }
 
 // fn should_ignore_file(ignore_file: &gitignore::File, entry: &DirEntry) -> bool {
@@ -59,7 +132,24 @@ fn has_tsync_attribute(attributes: &[syn::Attribute]) -> bool {
     utils::has_attribute("tsync", attributes)
 }
 
+/// Whether an item of the current module was selected for export without being
+/// marked with #[tsync], e.g. because an exported item references it
+fn is_exported(ident: &syn::Ident, state: &BuildState) -> bool {
+    let mut full_path = state.module_path.clone();
+    full_path.push(ident.to_string());
+    state.tsync_items.iter().any(|(path, _)| *path == full_path)
+}
+
 impl BuildState {
+    /// The type an optional type is joined with, following the nullability policy
+    fn optional_type(&self) -> &'static str {
+        if self.null_optionals {
+            "null"
+        } else {
+            "undefined"
+        }
+    }
+
     fn write_comments(&mut self, comments: &Vec<String>, indentation_amount: i8) {
         let indentation = utils::build_indentation(indentation_amount);
         match comments.len() {
@@ -82,25 +172,294 @@ impl BuildState {
     }
 }
 
+/// A parsed input file, along with the module path it's assumed to have in its crate
+struct RustFile {
+    path: PathBuf,
+    module_path: Vec<String>,
+    syntax: syn::File,
+}
+
+/// Derive the module path of a rust file from its location relative to the input
+/// directory, i.e. `models/v1.rs` and `models/v1/mod.rs` become `crate::models::v1`.
+fn module_path_for_file(root: &Path, file: &Path) -> Vec<String> {
+    let relative = file.strip_prefix(root).unwrap_or(file);
+    let mut module_path = vec!["crate".to_owned()];
+    module_path.extend(
+        relative
+            .with_extension("")
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned()),
+    );
+
+    if module_path.len() > 1
+        && matches!(
+            module_path.last().map(String::as_str),
+            Some("mod" | "lib" | "main")
+        )
+    {
+        module_path.pop();
+    }
+
+    module_path
+}
+
+/// Record the full path of every struct, enum and type alias so type references
+/// can be resolved against them, including the ones marked with #[tsync].
+fn index_rust_file(file: &RustFile, state: &mut BuildState, config: &BuildSettings) {
+    index_module(&file.syntax.items, &file.module_path, state, config);
+}
+
+/// Set the module path and the imports of the module whose items are processed next
+fn enter_module(items: &[syn::Item], module_path: &[String], state: &mut BuildState) {
+    state.module_path = module_path.to_vec();
+    state.imports.clear();
+    for item in items.iter() {
+        if let syn::Item::Use(item_use) = item {
+            utils::collect_use_paths(&item_use.tree, &mut Vec::new(), &mut state.imports);
+        }
+    }
+}
+
+fn index_module(
+    items: &[syn::Item],
+    module_path: &[String],
+    state: &mut BuildState,
+    config: &BuildSettings,
+) {
+    for item in items.iter() {
+        if let syn::Item::Mod(syn::ItemMod {
+            ident,
+            content: Some((_, items)),
+            ..
+        }) = item
+        {
+            index_module(items, &submodule_path(module_path, ident), state, config);
+        }
+    }
+
+    enter_module(items, module_path, state);
+    for item in items.iter() {
+        let (ident, attrs) = match item {
+            syn::Item::Struct(item) => (&item.ident, &item.attrs),
+            syn::Item::Enum(item) => (&item.ident, &item.attrs),
+            syn::Item::Type(item) => (&item.ident, &item.attrs),
+            _ => continue,
+        };
+
+        let mut full_path = module_path.to_vec();
+        full_path.push(ident.to_string());
+        state.declared_items.insert(full_path.clone());
+
+        if get_aliased_type(item)
+            .as_ref()
+            .is_some_and(utils::is_option_type)
+        {
+            state.optional_aliases.push(full_path.clone());
+        }
+
+        let is_serde_type = config
+            .serde_types
+            .as_ref()
+            .is_some_and(|serde_types| serde_types.selects(item, &full_path));
+        if has_tsync_attribute(attrs) || is_serde_type {
+            let ts_name = match utils::get_attribute_arg("tsync", "rename", attrs) {
+                Some(rename) if syn::parse_str::<syn::Ident>(&rename).is_ok() => rename,
+                Some(rename) => {
+                    state.diagnostics.push(format!(
+                        "#[tsync(rename = \"{rename}\")] was ignored on {ident}, since it isn't a valid identifier"
+                    ));
+                    ident.unraw().to_string()
+                }
+                None => ident.unraw().to_string(),
+            };
+            state.tsync_items.push((full_path, ts_name));
+        }
+    }
+}
+
+/// The type an item is serialized as, when it's an alias or newtype of another type
+fn get_aliased_type(item: &syn::Item) -> Option<syn::Type> {
+    match item {
+        syn::Item::Type(item) => Some(*item.ty.clone()),
+        syn::Item::Struct(item) => utils::get_serde_conversion_type(&item.attrs)
+            .or_else(|| utils::get_newtype_field(item).map(|field| field.ty.clone())),
+        syn::Item::Enum(item) => utils::get_serde_conversion_type(&item.attrs),
+        _ => None,
+    }
+}
+
+/// Record which of the declared items aren't serialized as objects and which are serialized
+/// as null. This is done once every item is declared, since aliases may refer to items
+/// declared after them, and repeated until nothing changes to follow aliases of aliases.
+fn classify_rust_files(files: &[RustFile], state: &mut BuildState) {
+    loop {
+        let classified = state.non_object_items.len() + state.unit_items.len();
+        for file in files {
+            classify_module(&file.syntax.items, &file.module_path, state);
+        }
+        if state.non_object_items.len() + state.unit_items.len() == classified {
+            return;
+        }
+    }
+}
+
+fn classify_module(items: &[syn::Item], module_path: &[String], state: &mut BuildState) {
+    for item in items.iter() {
+        if let syn::Item::Mod(syn::ItemMod {
+            ident,
+            content: Some((_, items)),
+            ..
+        }) = item
+        {
+            classify_module(items, &submodule_path(module_path, ident), state);
+        }
+    }
+
+    enter_module(items, module_path, state);
+    for item in items.iter() {
+        let (ident, attrs) = match item {
+            syn::Item::Struct(item) => (&item.ident, &item.attrs),
+            syn::Item::Enum(item) => (&item.ident, &item.attrs),
+            syn::Item::Type(item) => (&item.ident, &item.attrs),
+            _ => continue,
+        };
+
+        let mut full_path = module_path.to_vec();
+        full_path.push(ident.to_string());
+        let aliased_type = get_aliased_type(item);
+
+        let is_non_object = match (item, &aliased_type) {
+            (_, Some(ty)) => typescript::is_non_object_type(ty, state),
+            (syn::Item::Struct(item), None) => matches!(item.fields, syn::Fields::Unnamed(_)),
+            // externally tagged enums with only unit variants are serialized as strings
+            (syn::Item::Enum(item), None) => {
+                !utils::has_attribute_arg("serde", "tag", attrs)
+                    && !utils::has_attribute_arg("serde", "untagged", attrs)
+                    && item
+                        .variants
+                        .iter()
+                        .all(|variant| matches!(variant.fields, syn::Fields::Unit))
+            }
+            _ => false,
+        };
+        if is_non_object && !state.non_object_items.contains(&full_path) {
+            state.non_object_items.push(full_path.clone());
+        }
+
+        let is_unit = match (item, &aliased_type) {
+            (_, Some(ty)) => typescript::is_unit_type(ty, state),
+            (syn::Item::Struct(item), None) => matches!(item.fields, syn::Fields::Unit),
+            _ => false,
+        };
+        if is_unit && !state.unit_items.contains(&full_path) {
+            state.unit_items.push(full_path);
+        }
+    }
+}
+
+/// Give #[tsync] items which share a name in different modules distinct typescript
+/// names, by prefixing as many of their module names as needed.
+/// e.g. `crate::models::v1::User` and `crate::models::v2::User` become `V1User` and `V2User`.
+/// Items renamed with `#[tsync(rename = "...")]` keep their name.
+fn disambiguate_tsync_item_names(state: &mut BuildState) {
+    // number of module names prefixed to each item's name
+    let mut prefix_lens = vec![0; state.tsync_items.len()];
+
+    loop {
+        let names = state
+            .tsync_items
+            .iter()
+            .zip(prefix_lens.iter())
+            .map(|((item_path, ts_name), prefix_len)| {
+                // skip the leading `crate` and the trailing item name
+                let modules = &item_path[1..item_path.len() - 1];
+                modules[modules.len() - prefix_len..]
+                    .iter()
+                    .map(|module| module.to_case(Case::Pascal))
+                    .chain(Some(ts_name.clone()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut changed = false;
+        for (index, (item_path, ts_name)) in state.tsync_items.iter().enumerate() {
+            let clashes = names
+                .iter()
+                .enumerate()
+                .any(|(other, name)| other != index && *name == names[index]);
+            let is_renamed = item_path.last().unwrap().trim_start_matches("r#") != ts_name;
+            if clashes && !is_renamed && prefix_lens[index] < item_path.len() - 2 {
+                prefix_lens[index] += 1;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            for ((_, ts_name), name) in state.tsync_items.iter_mut().zip(names) {
+                *ts_name = name;
+            }
+            return;
+        }
+    }
+}
+
+/// Prepare the state for converting an item: track its generic parameters (which
+/// shadow imported types of the same name), and rename it if its name clashes
+/// with a #[tsync] item in another module.
+fn enter_item(ident: &mut syn::Ident, generics: &syn::Generics, state: &mut BuildState) {
+    set_type_params(generics, state);
+
+    let mut full_path = state.module_path.clone();
+    full_path.push(ident.to_string());
+    if let Some((_, ts_name)) = state
+        .tsync_items
+        .iter()
+        .find(|(path, _)| *path == full_path)
+    {
+        if ident.unraw() != ts_name {
+            *ident = syn::Ident::new(ts_name, ident.span());
+        }
+    }
+}
+
+fn set_type_params(generics: &syn::Generics, state: &mut BuildState) {
+    state.type_params = generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .chain(generics.const_params().map(|param| param.ident.to_string()))
+        .collect();
+}
+
 fn process_rust_item(item: syn::Item, state: &mut BuildState, config: &BuildSettings) {
     match item {
         syn::Item::Const(exported_const) => {
-            check_tsync!(exported_const, in: "const", {
+            check_tsync!(exported_const, in: "const", state, {
                 exported_const.convert_to_ts(state, config);
             });
         }
-        syn::Item::Struct(exported_struct) => {
-            check_tsync!(exported_struct, in: "struct", {
+        syn::Item::Struct(mut exported_struct) => {
+            check_tsync!(exported_struct, in: "struct", state, {
+                enter_item(&mut exported_struct.ident, &exported_struct.generics, state);
                 exported_struct.convert_to_ts(state, config);
             });
         }
-        syn::Item::Enum(exported_enum) => {
-            check_tsync!(exported_enum, in: "enum", {
+        syn::Item::Enum(mut exported_enum) => {
+            check_tsync!(exported_enum, in: "enum", state, {
+                enter_item(&mut exported_enum.ident, &exported_enum.generics, state);
                 exported_enum.convert_to_ts(state, config);
             });
         }
-        syn::Item::Type(exported_type) => {
-            check_tsync!(exported_type, in: "type", {
+        syn::Item::Fn(exported_fn) => {
+            let is_selected_command =
+                to_typescript::fns::is_selected_command(&exported_fn.attrs, config);
+            check_tsync!(exported_fn, ident: exported_fn.sig.ident, in: "fn", state, is_selected_command, {
+                set_type_params(&exported_fn.sig.generics, state);
+                exported_fn.convert_to_ts(state, config);
+            });
+        }
+        syn::Item::Type(mut exported_type) => {
+            check_tsync!(exported_type, in: "type", state, {
+                enter_item(&mut exported_type.ident, &exported_type.generics, state);
                 exported_type.convert_to_ts(state, config);
             });
         }
@@ -108,29 +467,71 @@ fn process_rust_item(item: syn::Item, state: &mut BuildState, config: &BuildSett
     }
 }
 
-fn process_rust_file<P: AsRef<Path>>(
+fn parse_rust_file<P: AsRef<Path>>(
     input_path: P,
+    module_path: Vec<String>,
     state: &mut BuildState,
-    config: &BuildSettings,
-) {
-    if *DEBUG.get() {
-        println!("processing rust file: {:?}", input_path.as_ref().to_str());
-    }
-
+) -> Option<RustFile> {
     let Ok(src) = std::fs::read_to_string(input_path.as_ref()) else {
         state.unprocessed_files.push(input_path.as_ref().to_path_buf());
-        return;
+        return None;
     };
 
-    let Ok(syntax) = syn::parse_file(&src) else {
+    let Ok(mut syntax) = syn::parse_file(&src) else {
         state.unprocessed_files.push(input_path.as_ref().to_path_buf());
-        return;
+        return None;
     };
+    cfg::apply_cfg(&mut syntax.items, state.cfg.as_ref());
 
-    syntax
-        .items
-        .into_iter()
-        .for_each(|item| process_rust_item(item, state, config))
+    Some(RustFile {
+        path: input_path.as_ref().to_path_buf(),
+        module_path,
+        syntax,
+    })
+}
+
+fn process_rust_file(file: RustFile, state: &mut BuildState, config: &BuildSettings) {
+    if *DEBUG.get() {
+        println!("processing rust file: {:?}", file.path.to_str());
+    }
+
+    process_module(file.syntax.items, &file.module_path, state, config);
+}
+
+fn process_module(
+    items: Vec<syn::Item>,
+    module_path: &[String],
+    state: &mut BuildState,
+    config: &BuildSettings,
+) {
+    enter_module(&items, module_path, state);
+    let imports = state.imports.clone();
+    for item in items {
+        // items of inline modules are processed in place, so the output follows the source
+        if let syn::Item::Mod(syn::ItemMod {
+            ident,
+            content: Some((_, module_items)),
+            ..
+        }) = item
+        {
+            process_module(
+                module_items,
+                &submodule_path(module_path, &ident),
+                state,
+                config,
+            );
+            state.module_path = module_path.to_vec();
+            state.imports = imports.clone();
+            continue;
+        }
+        process_rust_item(item, state, config);
+    }
+}
+
+fn submodule_path(module_path: &[String], ident: &syn::Ident) -> Vec<String> {
+    let mut submodule_path = module_path.to_vec();
+    submodule_path.push(ident.unraw().to_string());
+    submodule_path
 }
 
 fn check_path<P: AsRef<Path>>(path: P, state: &mut BuildState) -> bool {
@@ -177,7 +578,142 @@ fn validate_dir_entry(entry_result: walkdir::Result<DirEntry>, path: &Path) -> O
     }
 }
 
-fn process_dir_entry<P: AsRef<Path>>(path: P, state: &mut BuildState, config: &BuildSettings) {
+/// Parse the files of a crate, starting at its root file (`lib.rs` or `main.rs`, which is
+/// looked for in `src` when given a directory) and following its `mod foo;` declarations.
+fn parse_crate(path: &Path, state: &mut BuildState, files: &mut Vec<RustFile>) {
+    let root = if path.is_dir() {
+        ["src/lib.rs", "src/main.rs", "lib.rs", "main.rs"]
+            .iter()
+            .map(|root| path.join(root))
+            .find(|root| root.is_file())
+    } else {
+        Some(path.to_path_buf())
+    };
+    let Some(root) = root else {
+        if *DEBUG.get() {
+            println!("No crate root found in `{:#?}`", path);
+        }
+        state.unprocessed_files.push(path.to_path_buf());
+        return;
+    };
+
+    let dir = root.parent().unwrap_or(Path::new("")).to_path_buf();
+    let mut visited = HashSet::new();
+    parse_module_file(
+        &root,
+        vec!["crate".to_owned()],
+        dir,
+        state,
+        files,
+        &mut visited,
+    );
+}
+
+/// Parse the file of a module and the files of the modules it declares. `dir` is the
+/// directory where the files of its submodules are, e.g. `src/models` for `src/models.rs`.
+fn parse_module_file(
+    path: &Path,
+    module_path: Vec<String>,
+    dir: PathBuf,
+    state: &mut BuildState,
+    files: &mut Vec<RustFile>,
+    visited: &mut HashSet<PathBuf>,
+) {
+    // a module can be declared twice with `#[path]`, but it only needs to be read once
+    if !visited.insert(path.canonicalize().unwrap_or(path.to_path_buf())) {
+        return;
+    }
+
+    let Some(file) = parse_rust_file(path, module_path, state) else {
+        return;
+    };
+
+    let file_dir = path.parent().unwrap_or(Path::new(""));
+    let mut declarations = Vec::new();
+    collect_mod_declarations(
+        &file.syntax.items,
+        &file.module_path,
+        &dir,
+        file_dir,
+        &mut declarations,
+    );
+    files.push(file);
+
+    for (module_path, path, dir) in declarations {
+        parse_module_file(&path, module_path, dir, state, files, visited);
+    }
+}
+
+/// Find the `mod foo;` declarations of a file (including the ones in its inline modules),
+/// along with the module path, the file and the submodule directory of each of them.
+/// `path_dir` is the directory `#[path]` attributes are relative to: the directory of
+/// the file, or the submodule directory inside inline modules.
+fn collect_mod_declarations(
+    items: &[syn::Item],
+    module_path: &[String],
+    dir: &Path,
+    path_dir: &Path,
+    declarations: &mut Vec<(Vec<String>, PathBuf, PathBuf)>,
+) {
+    for item in items.iter() {
+        let syn::Item::Mod(item_mod) = item else {
+            continue;
+        };
+        let name = item_mod.ident.unraw().to_string();
+        let path_attr = get_path_attribute(&item_mod.attrs);
+
+        match &item_mod.content {
+            Some((_, items)) => {
+                // `#[path]` on inline modules sets the directory of their submodules
+                let dir = dir.join(path_attr.unwrap_or(name.clone()));
+                let module_path = submodule_path(module_path, &item_mod.ident);
+                collect_mod_declarations(items, &module_path, &dir, &dir, declarations);
+            }
+            None => {
+                let (path, submodule_dir) = match path_attr {
+                    Some(path_attr) => {
+                        let path = path_dir.join(path_attr);
+                        let submodule_dir = path.parent().unwrap_or(Path::new("")).to_path_buf();
+                        (path, submodule_dir)
+                    }
+                    None => {
+                        let file = dir.join(format!("{name}.rs"));
+                        let mod_file = dir.join(&name).join("mod.rs");
+                        let path = if !file.exists() && mod_file.exists() {
+                            mod_file
+                        } else {
+                            file
+                        };
+                        (path, dir.join(&name))
+                    }
+                };
+                declarations.push((
+                    submodule_path(module_path, &item_mod.ident),
+                    path,
+                    submodule_dir,
+                ));
+            }
+        }
+    }
+}
+
+/// The value of a `#[path = "..."]` attribute
+fn get_path_attribute(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            path,
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                }),
+            ..
+        }) if path.is_ident("path") => Some(value.value()),
+        _ => None,
+    })
+}
+
+fn parse_dir_entry<P: AsRef<Path>>(path: P, state: &mut BuildState, files: &mut Vec<RustFile>) {
     WalkDir::new(path.as_ref())
         .sort_by_file_name()
         .into_iter()
@@ -189,7 +725,8 @@ fn process_dir_entry<P: AsRef<Path>>(path: P, state: &mut BuildState, config: &B
                 .extension()
                 .is_some_and(|extension| check_extension(extension, path.as_ref()))
             {
-                process_rust_file(entry.path(), state, config);
+                let module_path = module_path_for_file(path.as_ref(), entry.path());
+                files.extend(parse_rust_file(entry.path(), module_path, state));
             }
         })
 }
@@ -199,35 +736,88 @@ pub fn generate_typescript_defs(
     output: PathBuf,
     debug: bool,
     enable_const_enums: bool,
+) {
+    let config = BuildSettings {
+        enable_const_enums,
+        ..Default::default()
+    };
+
+    generate_typescript_defs_with_settings(input, output, debug, config)
+}
+
+/// Same as [`generate_typescript_defs`], but with all the [`BuildSettings`] available.
+/// `uses_type_interface` is always determined by the extension of the output file.
+pub fn generate_typescript_defs_with_settings(
+    input: Vec<PathBuf>,
+    output: PathBuf,
+    debug: bool,
+    mut config: BuildSettings,
 ) {
     DEBUG.set(debug);
 
-    let uses_type_interface = output
+    config.uses_type_interface = output
         .to_str()
         .map(|x| x.ends_with(".d.ts"))
         .unwrap_or(true);
 
-    let config = BuildSettings {
-        uses_type_interface,
-        enable_const_enums,
+    let mut state = BuildState {
+        null_optionals: config.enable_null_optionals,
+        cfg: config.cfg.clone(),
+        ..Default::default()
     };
 
-    let mut state = BuildState::default();
-
     state
         .types
         .push_str("/* This file is generated and managed by tsync */\n");
 
+    let mut files = Vec::new();
     input.into_iter().for_each(|path| {
         if check_path(&path, &mut state) {
-            if path.is_dir() {
-                process_dir_entry(&path, &mut state, &config)
+            if config.enable_crate_root {
+                parse_crate(&path, &mut state, &mut files)
+            } else if path.is_dir() {
+                parse_dir_entry(&path, &mut state, &mut files)
             } else {
-                process_rust_file(&path, &mut state, &config);
+                let module_path = vec!["crate".to_owned()];
+                files.extend(parse_rust_file(&path, module_path, &mut state));
             }
         }
     });
 
+    files
+        .iter()
+        .for_each(|file| index_rust_file(file, &mut state, &config));
+    classify_rust_files(&files, &mut state);
+    if config.enable_referenced_items {
+        references::include_referenced_items(&files, &mut state, &config);
+    }
+    disambiguate_tsync_item_names(&mut state);
+
+    files
+        .into_iter()
+        .for_each(|file| process_rust_file(file, &mut state, &config));
+
+    if state.uses_json_value {
+        let export = if config.uses_type_interface { "" } else { "export " };
+        let header_len = state.types.find('\n').map_or(0, |i| i + 1);
+        state.types.insert_str(
+            header_len,
+            &format!("\n{}", typescript::json_value_type_definition(export)),
+        );
+    }
+
+    if state.uses_tauri_invoke {
+        let module = config
+            .tauri_invoke_module
+            .as_deref()
+            .unwrap_or(to_typescript::fns::TAURI_INVOKE_MODULE);
+        let header_len = state.types.find('\n').map_or(0, |i| i + 1);
+        state.types.insert_str(
+            header_len,
+            &format!("\nimport {{ invoke }} from \"{module}\";\n"),
+        );
+    }
+
     if debug {
         println!("======================================");
         println!("FINAL FILE:");
@@ -269,4 +859,24 @@ pub fn generate_typescript_defs(
     for unprocessed_file in state.unprocessed_files {
         println!("• {:#?}", unprocessed_file);
     }
+
+    if !state.referenced_items.is_empty() {
+        println!("The following items were exported since other exported items reference them:");
+    }
+
+    for (item_path, referenced_by) in state.referenced_items {
+        println!(
+            "• {} (referenced by {})",
+            references::format_item_path(&item_path),
+            references::format_item_path(&referenced_by)
+        );
+    }
+
+    if !state.diagnostics.is_empty() {
+        println!("The following problems were found while generating types:");
+    }
+
+    for diagnostic in state.diagnostics {
+        println!("• {}", diagnostic);
+    }
 }
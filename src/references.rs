@@ -0,0 +1,195 @@
+//! Exporting the items referenced by exported items, so that marking a root type with
+//! `#[tsync]` is enough to get the types of its fields and variants (and theirs, and so
+//! on) instead of references to types which aren't defined in the output.
+
+use std::collections::HashSet;
+
+use syn::visit::Visit;
+
+use crate::to_typescript::fns;
+use crate::{typescript, utils, BuildSettings, BuildState, RustFile};
+
+/// Add the items defined in the input which are referenced by exported items (or by the
+/// signatures of exported functions) to the exported items, until every referenced item
+/// is exported
+pub(crate) fn include_referenced_items(
+    files: &[RustFile],
+    state: &mut BuildState,
+    config: &BuildSettings,
+) {
+    let mut visited = HashSet::new();
+
+    loop {
+        let mut referenced = Vec::new();
+        for file in files {
+            collect_references(
+                &file.syntax.items,
+                &file.module_path,
+                state,
+                config,
+                &mut visited,
+                &mut referenced,
+            );
+        }
+
+        let mut included = false;
+        for (item_path, referenced_by) in referenced {
+            if state.tsync_items.iter().any(|(path, _)| *path == item_path) {
+                continue;
+            }
+            let ts_name = item_path
+                .last()
+                .unwrap()
+                .trim_start_matches("r#")
+                .to_owned();
+            state.tsync_items.push((item_path.clone(), ts_name));
+            state.referenced_items.push((item_path, referenced_by));
+            included = true;
+        }
+
+        if !included {
+            return;
+        }
+    }
+}
+
+/// Collect the items referenced by the exported items of a module which weren't visited yet,
+/// along with the path of the item referencing them
+fn collect_references(
+    items: &[syn::Item],
+    module_path: &[String],
+    state: &mut BuildState,
+    config: &BuildSettings,
+    visited: &mut HashSet<Vec<String>>,
+    referenced: &mut Vec<(Vec<String>, Vec<String>)>,
+) {
+    for item in items.iter() {
+        if let syn::Item::Mod(syn::ItemMod {
+            ident,
+            content: Some((_, items)),
+            ..
+        }) = item
+        {
+            let module_path = crate::submodule_path(module_path, ident);
+            collect_references(items, &module_path, state, config, visited, referenced);
+        }
+    }
+
+    crate::enter_module(items, module_path, state);
+    for item in items.iter() {
+        let (ident, generics, attrs) = match item {
+            syn::Item::Struct(item) => (&item.ident, &item.generics, &item.attrs),
+            syn::Item::Enum(item) => (&item.ident, &item.generics, &item.attrs),
+            syn::Item::Type(item) => (&item.ident, &item.generics, &item.attrs),
+            syn::Item::Fn(item) => (&item.sig.ident, &item.sig.generics, &item.attrs),
+            _ => continue,
+        };
+
+        let mut item_path = module_path.to_vec();
+        item_path.push(ident.to_string());
+        let is_exported = match item {
+            syn::Item::Fn(_) => {
+                crate::has_tsync_attribute(attrs) || fns::is_selected_command(attrs, config)
+            }
+            _ => state.tsync_items.iter().any(|(path, _)| *path == item_path),
+        };
+        if visited.contains(&item_path) || !is_exported {
+            continue;
+        }
+        visited.insert(item_path.clone());
+
+        // items given a typescript type don't reference anything
+        if utils::has_attribute_arg("tsync", "type", attrs) {
+            continue;
+        }
+
+        crate::set_type_params(generics, state);
+        let mut collector = TypeCollector {
+            state,
+            paths: Vec::new(),
+        };
+        for ty in get_referenced_types(item, config) {
+            collector.visit_type(&ty);
+        }
+
+        referenced.extend(
+            collector
+                .paths
+                .into_iter()
+                .filter(|path| *path != item_path)
+                .map(|path| (path, item_path.clone())),
+        );
+    }
+}
+
+/// The types an item is serialized with: the types of its fields and variants, or
+/// the type it's an alias of or converted into. For functions, the types of their
+/// arguments and return type.
+fn get_referenced_types(item: &syn::Item, config: &BuildSettings) -> Vec<syn::Type> {
+    let is_kept = |attrs: &[syn::Attribute]| !utils::has_attribute_arg("tsync", "skip", attrs);
+    let field_types = |fields: &syn::Fields| {
+        fields
+            .iter()
+            .filter(|field| is_kept(&field.attrs))
+            .map(|field| field.ty.clone())
+            .collect::<Vec<_>>()
+    };
+
+    match item {
+        syn::Item::Struct(item) => match utils::get_serde_conversion_type(&item.attrs) {
+            Some(ty) => vec![ty],
+            None => field_types(&item.fields),
+        },
+        syn::Item::Enum(item) => match utils::get_serde_conversion_type(&item.attrs) {
+            Some(ty) => vec![ty],
+            None => item
+                .variants
+                .iter()
+                .filter(|variant| is_kept(&variant.attrs))
+                .flat_map(|variant| field_types(&variant.fields))
+                .collect(),
+        },
+        syn::Item::Type(item) => vec![*item.ty.clone()],
+        syn::Item::Fn(item) => fns::get_signature_types(&item.sig, config),
+        _ => Vec::new(),
+    }
+}
+
+/// Collects the full paths of the items defined in the input which a type refers to,
+/// including the ones in its generic arguments (e.g. `Address` in `Vec<Address>`)
+struct TypeCollector<'a> {
+    state: &'a BuildState,
+    paths: Vec<Vec<String>>,
+}
+
+impl<'ast> Visit<'ast> for TypeCollector<'_> {
+    fn visit_type_path(&mut self, type_path: &'ast syn::TypePath) {
+        let full_path = typescript::resolve_path(&type_path.path, self.state);
+        let is_type_param = full_path.len() == 1 && self.state.type_params.contains(&full_path[0]);
+        if is_type_param {
+            return;
+        }
+        // built-in types are never items of the input, even when one has the same name,
+        // like a `type Result<T> = ...` alias, but their arguments may be
+        if typescript::is_builtin_path(&full_path, self.state) {
+            syn::visit::visit_type_path(self, type_path);
+            return;
+        }
+        if let Some(item_path) = typescript::find_declared_item(&full_path, self.state) {
+            if !self.paths.contains(item_path) {
+                self.paths.push(item_path.clone());
+            }
+        }
+
+        syn::visit::visit_type_path(self, type_path);
+    }
+}
+
+/// Format an item path for reports, e.g. `crate::models::User`
+pub(crate) fn format_item_path(item_path: &[String]) -> String {
+    item_path
+        .iter()
+        .map(|segment| segment.trim_start_matches("r#"))
+        .collect::<Vec<_>>()
+        .join("::")
+}
@@ -0,0 +1,112 @@
+//! Selecting items to export without marking them with `#[tsync]`, for crates whose
+//! source can't (or shouldn't) be changed.
+
+use crate::utils;
+
+/// Export the structs and enums deriving `Serialize` or `Deserialize`, as if they were
+/// marked with `#[tsync]`. Type aliases can't derive anything, so they still need
+/// `#[tsync]`, unless they're exported with `enable_referenced_items`.
+#[derive(Clone, Debug, Default)]
+pub struct SerdeTypes {
+    /// only export the items declared `pub`
+    pub pub_only: bool,
+    /// only export the items whose full path matches one of these globs, e.g.
+    /// `crate::models::*` for the items of `models` or `crate::api::**` for the items of
+    /// `api` and its submodules. All items are exported when it's empty.
+    pub paths: Vec<String>,
+}
+
+impl SerdeTypes {
+    pub(crate) fn selects(&self, item: &syn::Item, full_path: &[String]) -> bool {
+        let (vis, attrs) = match item {
+            syn::Item::Struct(item) => (&item.vis, &item.attrs),
+            syn::Item::Enum(item) => (&item.vis, &item.attrs),
+            _ => return false,
+        };
+
+        let derives_serde =
+            utils::has_derive("Serialize", attrs) || utils::has_derive("Deserialize", attrs);
+
+        derives_serde
+            && (!self.pub_only || matches!(vis, syn::Visibility::Public(_)))
+            && (self.paths.is_empty()
+                || self.paths.iter().any(|glob| matches_glob(glob, full_path)))
+    }
+}
+
+/// Match a full path against a glob like `crate::models::**::*Dto`, where `*` matches
+/// any part of a single segment and `**` matches any number of segments
+fn matches_glob(glob: &str, full_path: &[String]) -> bool {
+    let glob = glob.split("::").collect::<Vec<_>>();
+    let full_path = full_path
+        .iter()
+        .map(|segment| segment.trim_start_matches("r#"))
+        .collect::<Vec<_>>();
+    matches_segments(&glob, &full_path)
+}
+
+fn matches_segments(glob: &[&str], path: &[&str]) -> bool {
+    match (glob.first(), path.first()) {
+        (None, None) => true,
+        (Some(&"**"), _) => {
+            matches_segments(&glob[1..], path)
+                || (!path.is_empty() && matches_segments(glob, &path[1..]))
+        }
+        (Some(pattern), Some(segment)) => {
+            matches_segment(pattern, segment) && matches_segments(&glob[1..], &path[1..])
+        }
+        _ => false,
+    }
+}
+
+fn matches_segment(pattern: &str, segment: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == segment,
+        Some((prefix, rest)) => {
+            segment.starts_with(prefix)
+                && (0..=segment.len() - prefix.len())
+                    .filter(|&i| segment.is_char_boundary(prefix.len() + i))
+                    .any(|i| matches_segment(rest, &segment[prefix.len() + i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_glob;
+
+    fn path(path: &str) -> Vec<String> {
+        path.split("::").map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn globs() {
+        assert!(matches_glob(
+            "crate::models::*",
+            &path("crate::models::User")
+        ));
+        assert!(!matches_glob(
+            "crate::models::*",
+            &path("crate::models::v1::User")
+        ));
+        assert!(matches_glob(
+            "crate::models::**",
+            &path("crate::models::v1::User")
+        ));
+        assert!(matches_glob("crate::**::User", &path("crate::User")));
+        assert!(matches_glob(
+            "crate::**::*Dto",
+            &path("crate::api::v2::UserDto")
+        ));
+        assert!(!matches_glob(
+            "crate::**::*Dto",
+            &path("crate::api::v2::DtoUser")
+        ));
+        assert!(matches_glob(
+            "crate::api::*::User*",
+            &path("crate::api::v1::UserResponse")
+        ));
+        assert!(matches_glob("**", &path("crate::r#type::User")));
+        assert!(matches_glob("crate::type::*", &path("crate::r#type::User")));
+    }
+}
@@ -17,7 +17,11 @@ impl super::ToTypescript for syn::ItemConst {
         // however doesn't enforce that the json! macro contains no variables.
         // if your lucky you might have also tsynced them but otherwise you will get a typescript error.
 
-        let name = self.ident.unraw().to_string();
+        let name = utils::get_attribute_arg("tsync", "rename", &self.attrs)
+            .unwrap_or_else(|| self.ident.unraw().to_string());
+        let ts_type = utils::get_attribute_arg("tsync", "type", &self.attrs)
+            .map(|ts_type| format!(": {ts_type}"))
+            .unwrap_or_default();
         let body = match self.expr.as_ref() {
             syn::Expr::Lit(literal) => {
                 // convert it directly to a string to put in TS.
@@ -45,7 +49,7 @@ impl super::ToTypescript for syn::ItemConst {
                 state.write_comments(&comments, 0);
                 state
                     .types
-                    .push_str(&format!("export const {} = {};", name, body));
+                    .push_str(&format!("export const {}{} = {};", name, ts_type, body));
                 state.types.push('\n');
             }
             _ => {
@@ -1,48 +1,82 @@
-use crate::{typescript::convert_type, utils, BuildState};
-use convert_case::{Case, Casing};
+use crate::case::RenameRule;
+use crate::typescript::{convert_type, is_non_object_type, is_unit_type};
+use crate::{utils, BuildState};
 use syn::__private::ToTokens;
 use syn::ext::IdentExt;
 
 /// Conversion of Rust Enum to Typescript using external tagging as per https://serde.rs/enum-representations.html
-/// however conversion will adhere to the `serde` `tag` such that enums are intenrally tagged
-/// (while the other forms such as adjacent tagging aren't supported).
+/// however conversion will adhere to the `serde` `tag` such that enums are intenrally tagged,
+/// or adjacently tagged when `content` is given as well.
 /// `rename_all` attributes for the name of the tag will also be adhered to.
 impl super::ToTypescript for syn::ItemEnum {
-    fn convert_to_ts(self, state: &mut BuildState, config: &crate::BuildSettings) {
+    fn convert_to_ts(mut self, state: &mut BuildState, config: &crate::BuildSettings) {
         state.types.push('\n');
 
         let comments = utils::get_comments(self.clone().attrs);
-        let casing = utils::get_attribute_arg("serde", "rename_all", &self.attrs);
-        let casing = utils::parse_serde_case(casing);
+        state.write_comments(&comments, 0);
+
+        // items can be given any typescript type with `#[tsync(type = "...")]`
+        if let Some(ts_type) = utils::get_attribute_arg("tsync", "type", &self.attrs) {
+            super::add_ts_type_alias(&self.ident, &self.generics, &ts_type, state, config);
+            return;
+        }
+
+        // numeric enums keep their skipped variants until numbering, as those still take up a value
+        let is_numeric = utils::has_attribute_arg("derive", "Serialize_repr", &self.attrs);
+        self.variants = std::mem::take(&mut self.variants)
+            .into_iter()
+            .filter(|variant| {
+                is_numeric || !utils::has_attribute_arg("tsync", "skip", &variant.attrs)
+            })
+            .map(|mut variant| {
+                utils::remove_skipped_fields(&mut variant.fields);
+                variant
+            })
+            .collect();
+
+        // `rename_all` renames the variants of an enum, while `rename_all_fields`
+        // renames the fields of its struct variants
+        let casing = utils::get_serde_case("rename_all", &self.attrs);
+        let fields_casing = utils::get_serde_case("rename_all_fields", &self.attrs);
 
         // is_single means the enum has no variants with fields
         // i.e. `enum Foo { Bar, Baz }` rather than `enum Foo { Bar, Baz(String) }`
         let is_single = !self.variants.iter().any(|x| !x.fields.is_empty());
-        state.write_comments(&comments, 0);
+
+        // enums converted with `#[serde(into = "...")]` are serialized as that type
+        if let Some(ty) = utils::get_serde_conversion_type(&self.attrs) {
+            super::add_type_alias(&self.ident, &self.generics, &ty, state, config);
+            return;
+        }
 
         // Handle untagged enum if serde has the tag untagged
         if utils::get_attribute_arg("serde", "untagged", &self.attrs).is_some() {
-            add_untagged_tagged_enum(self, state, casing, config.uses_type_interface);
+            add_untagged_tagged_enum(self, state, fields_casing, config);
         }
         // always use output the internally_tagged representation if the tag is present
         else if let Some(tag_name) = utils::get_attribute_arg("serde", "tag", &self.attrs) {
-            let content_name = utils::get_attribute_arg("serde", "content", &self.attrs);
-            add_internally_tagged_enum(
-                tag_name,
-                content_name,
-                self,
-                state,
-                casing,
-                config.uses_type_interface,
-            )
+            match utils::get_attribute_arg("serde", "content", &self.attrs) {
+                Some(content_name) => add_adjacently_tagged_enum(
+                    tag_name,
+                    content_name,
+                    self,
+                    state,
+                    casing,
+                    fields_casing,
+                    config,
+                ),
+                None => {
+                    add_internally_tagged_enum(tag_name, self, state, casing, fields_casing, config)
+                }
+            }
         } else if is_single {
-            if utils::has_attribute_arg("derive", "Serialize_repr", &self.attrs) {
+            if is_numeric {
                 add_numeric_enum(self, state, casing, config)
             } else {
-                add_enum(self, state, casing, config.uses_type_interface)
+                add_enum(self, state, casing, config)
             }
         } else {
-            add_externally_tagged_enum(self, state, casing, config.uses_type_interface)
+            add_externally_tagged_enum(self, state, casing, fields_casing, config)
         }
     }
 }
@@ -52,23 +86,25 @@ impl super::ToTypescript for syn::ItemEnum {
 fn add_enum(
     exported_struct: syn::ItemEnum,
     state: &mut BuildState,
-    casing: Option<Case>,
-    uses_type_interface: bool,
+    casing: Option<RenameRule>,
+    config: &crate::BuildSettings,
 ) {
-    let export = if uses_type_interface { "" } else { "export " };
+    let export = if config.uses_type_interface { "" } else { "export " };
+    let generics = utils::format_generics_declaration(&exported_struct.generics, state, config);
     state.types.push_str(&format!(
-        "{export}type {interface_name} =\n{space}",
+        "{export}type {interface_name}{generics} =\n{space}",
         interface_name = exported_struct.ident,
         space = utils::build_indentation(1)
     ));
 
     for variant in exported_struct.variants {
-        let field_name = if let Some(casing) = casing {
-            variant.ident.unraw().to_string().to_case(casing)
+        // untagged unit variants are serialized as null
+        let variant_tag = if is_untagged_variant(&variant) {
+            "null".to_owned()
         } else {
-            variant.ident.unraw().to_string()
+            get_variant_tag(&variant, casing)
         };
-        state.types.push_str(&format!(" | \"{}\"", field_name));
+        state.types.push_str(&format!(" | {}", variant_tag));
     }
 
     state.types.push_str(";\n");
@@ -76,6 +112,8 @@ fn add_enum(
 
 /// Numeric enums. These will be converted using enum syntax
 /// or const enum syntax, depending on the `const_enum` parameter.
+/// Typescript enums can't be generic, so generic parameters are left out
+/// (a unit-only enum can't make use of its type parameters anyway).
 ///
 /// # Examples
 ///
@@ -123,7 +161,7 @@ fn add_enum(
 fn add_numeric_enum(
     exported_struct: syn::ItemEnum,
     state: &mut BuildState,
-    casing: Option<Case>,
+    casing: Option<RenameRule>,
     config: &crate::BuildSettings,
 ) {
     let declare = if config.uses_type_interface {
@@ -144,17 +182,17 @@ fn add_numeric_enum(
     let mut num = 0;
 
     for variant in exported_struct.variants {
-        state.types.push('\n');
-        let field_name = if let Some(casing) = casing {
-            variant.ident.unraw().to_string().to_case(casing)
-        } else {
-            variant.ident.unraw().to_string()
-        };
-        if let Some((_, disc)) = variant.discriminant {
+        if let Some((_, disc)) = &variant.discriminant {
             if let Ok(new_disc) = disc.to_token_stream().to_string().parse::<i32>() {
                 num = new_disc;
             }
         }
+        if utils::has_attribute_arg("tsync", "skip", &variant.attrs) {
+            num += 1;
+            continue;
+        }
+        state.types.push('\n');
+        let field_name = get_variant_name(&variant, casing);
         state
             .types
             .push_str(&format!("  {} = {},", field_name, num));
@@ -200,149 +238,142 @@ fn add_numeric_enum(
 /// ```
 fn add_internally_tagged_enum(
     tag_name: String,
-    content_name: Option<String>,
     exported_struct: syn::ItemEnum,
     state: &mut BuildState,
-    casing: Option<Case>,
-    uses_type_interface: bool,
+    casing: Option<RenameRule>,
+    fields_casing: Option<RenameRule>,
+    config: &crate::BuildSettings,
 ) {
-    let export = if uses_type_interface { "" } else { "export " };
+    let export = if config.uses_type_interface { "" } else { "export " };
     let generics = utils::extract_struct_generics(exported_struct.generics.clone());
+    let generics_declaration =
+        utils::format_generics_declaration(&exported_struct.generics, state, config);
     state.types.push_str(&format!(
-        "{export}type {interface_name}{generics} =",
+        "{export}type {interface_name}{generics_declaration} =",
         interface_name = exported_struct.ident,
-        generics = utils::format_generics(&generics)
     ));
 
-    // a list of the generics for each variant, so we don't need to recalculate them
-    let mut variant_generics_list = Vec::new();
-
-    for variant in exported_struct.variants.iter() {
-        let variant_field_types = variant.fields.iter().map(|f| f.ty.to_owned());
-        let variant_generics = generics
-            .iter()
-            .filter(|gen| {
-                variant_field_types
-                    .clone()
-                    .any(|ty| utils::type_contains_ident(&ty, gen))
-            })
-            .cloned()
-            .collect::<Vec<_>>();
-
-        // Assumes that non-newtype tuple variants have already been filtered out
-        // TODO: Check for case where discriminant name matches an inner structure field name
-        // We should reject clashes
-        match &variant.fields {
-            syn::Fields::Unnamed(fields) if fields.unnamed.len() > 1 && content_name.is_none() => {
-                continue;
+    // serde can't represent tuple variants, newtype variants of non-object types or
+    // fields named like the tag with internal tagging, so those are reported and left out
+    let enum_ident = exported_struct.ident.clone();
+    let (untagged_variants, variants): (Vec<_>, Vec<_>) = exported_struct
+        .variants
+        .into_iter()
+        .partition(is_untagged_variant);
+    let variants = variants
+        .into_iter()
+        .filter(|variant| match &variant.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() != 1 => {
+                state.diagnostics.push(format!(
+                    "variant {}::{} was skipped: internally tagged enums can't have tuple variants, consider adding #[serde(content = \"...\")]",
+                    enum_ident, variant.ident
+                ));
+                false
             }
-            _ => {
-                variant_generics_list.push(variant_generics.clone());
-                state.types.push('\n');
-                state.types.push_str(&format!(
-                    "  | {interface_name}__{variant_name}{generics}",
-                    interface_name = exported_struct.ident,
-                    variant_name = variant.ident.unraw(),
-                    generics = utils::format_generics(&variant_generics)
-                ))
+            syn::Fields::Unnamed(fields) if is_non_object_type(&fields.unnamed[0].ty, state) => {
+                state.diagnostics.push(format!(
+                    "variant {}::{} was skipped: the newtype variants of internally tagged enums must contain a struct or map",
+                    enum_ident, variant.ident
+                ));
+                false
             }
-        }
+            // the tag and the fields share the same object, so their names can't clash
+            syn::Fields::Named(fields) => match fields
+                .named
+                .iter()
+                .find(|field| {
+                    let casing = get_fields_casing(variant, fields_casing);
+                    super::structs::get_field_name(field, casing) == tag_name
+                })
+            {
+                Some(field) => {
+                    state.diagnostics.push(format!(
+                        "variant {}::{} was skipped: its field `{}` clashes with the tag of the enum",
+                        enum_ident,
+                        variant.ident,
+                        field.ident.as_ref().unwrap()
+                    ));
+                    false
+                }
+                None => true,
+            },
+            _ => true,
+        })
+        .collect::<Vec<_>>();
+
+    // a list of the generics for each variant, so we don't need to recalculate them
+    let variant_generics_list = variants
+        .iter()
+        .map(|variant| get_variant_generics(variant, &generics))
+        .collect::<Vec<_>>();
+
+    for (variant, variant_generics) in variants.iter().zip(&variant_generics_list) {
+        state.types.push('\n');
+        state.types.push_str(&format!(
+            "  | {interface_name}__{variant_name}{generics}",
+            interface_name = exported_struct.ident,
+            variant_name = variant.ident.unraw(),
+            generics = utils::format_generics(variant_generics)
+        ))
+    }
+
+    // no value can be serialized if every variant was skipped
+    if variants.is_empty() && untagged_variants.is_empty() {
+        state.types.push_str(" never");
+    }
+
+    for variant in untagged_variants {
+        add_untagged_variant(variant, state, fields_casing);
     }
 
     state.types.push_str(";\n");
 
-    for (variant, generics) in exported_struct
-        .variants
-        .into_iter()
-        .zip(variant_generics_list)
-    {
+    for (variant, generics) in variants.into_iter().zip(variant_generics_list) {
         let generics = utils::format_generics(&generics);
+        let variant_tag = get_variant_tag(&variant, casing);
+        let variant_fields_casing = get_fields_casing(&variant, fields_casing);
 
-        match (&variant.fields, content_name.as_ref()) {
-            // adjacently tagged
-            (syn::Fields::Unnamed(fields), Some(content_name)) => {
-                state.types.push('\n');
-                let comments = utils::get_comments(variant.attrs);
-                state.write_comments(&comments, 0);
-                state.types.push_str(&format!(
-                    "type {interface_name}__{variant_name}{generics} = ",
-                    interface_name = exported_struct.ident,
-                    variant_name = variant.ident.unraw(),
-                ));
-                // add discriminant
-                state.types.push_str(&format!(
-                    "{{\n{indent}\"{tag_name}\": \"{}\";\n{indent}\"{content_name}\": ",
-                    variant.ident,
-                    indent = utils::build_indentation(2),
-                ));
-                super::structs::process_tuple_fields(fields.clone(), state);
-                state.types.push_str(";\n};");
-            }
-            // missing content name, but is a newtype variant
-            (syn::Fields::Unnamed(fields), None) if fields.unnamed.len() <= 1 => {
-                state.types.push('\n');
-                let comments = utils::get_comments(variant.attrs);
-                state.write_comments(&comments, 0);
-                state.types.push_str(&format!(
-                    "type {interface_name}__{variant_name}{generics} = ",
-                    interface_name = exported_struct.ident,
-                    variant_name = variant.ident.unraw(),
-                ));
-
-                let field_name = if let Some(casing) = casing {
-                    variant.ident.unraw().to_string().to_case(casing)
-                } else {
-                    variant.ident.unraw().to_string()
-                };
+        state.types.push('\n');
+        let comments = utils::get_comments(variant.attrs);
+        state.write_comments(&comments, 0);
+        state.types.push_str(&format!(
+            "type {interface_name}__{variant_name}{generics} = ",
+            interface_name = exported_struct.ident,
+            variant_name = variant.ident.unraw(),
+        ));
+
+        match variant.fields {
+            // newtype variant
+            syn::Fields::Unnamed(fields) => {
                 // add discriminant
                 state.types.push_str(&format!(
-                    "{{\n{}{}: \"{}\"}}",
+                    "{{\n{}\"{}\": {};\n}}",
                     utils::build_indentation(2),
                     tag_name,
-                    field_name,
+                    variant_tag,
                 ));
 
-                // add the newtype field
-                let newtype = convert_type(&fields.unnamed.first().unwrap().ty);
-                state.types.push_str(&format!(
-                    " & {content_name}",
-                    content_name = newtype.ts_type
-                ));
-            }
-            // missing content name, and is not a newtype, this is an error case
-            (syn::Fields::Unnamed(_), None) => {
-                if crate::DEBUG.try_get().is_some_and(|d: &bool| *d) {
-                    println!(
-                        "#[tsync] failed for {} variant of enum {}, missing content attribute, skipping",
-                        variant.ident,
-                        exported_struct.ident
-                    );
+                // add the newtype field, unless it's a unit which serde serializes as just the tag
+                let ty = &fields.unnamed[0].ty;
+                if !is_unit_type(ty, state) {
+                    let newtype = convert_type(ty, state);
+                    state.types.push_str(&format!(
+                        " & {content_name}",
+                        content_name = newtype.ts_type
+                    ));
                 }
-                continue;
+                state.types.push(';');
             }
-            _ => {
-                state.types.push('\n');
-                let comments = utils::get_comments(variant.attrs);
-                state.write_comments(&comments, 0);
-                state.types.push_str(&format!(
-                    "type {interface_name}__{variant_name}{generics} = ",
-                    interface_name = exported_struct.ident,
-                    variant_name = variant.ident.unraw(),
-                ));
-
-                let field_name = if let Some(casing) = casing {
-                    variant.ident.unraw().to_string().to_case(casing)
-                } else {
-                    variant.ident.unraw().to_string()
-                };
+            // unit and struct variants
+            fields => {
                 // add discriminant
                 state.types.push_str(&format!(
-                    "{{\n{}{}: \"{}\";\n",
+                    "{{\n{}\"{}\": {};\n",
                     utils::build_indentation(2),
                     tag_name,
-                    field_name,
+                    variant_tag,
                 ));
-                super::structs::process_fields(variant.fields, state, 2, casing, false);
+                super::structs::process_fields(fields, state, 2, variant_fields_casing, false);
                 state.types.push_str("};");
             }
         }
@@ -350,93 +381,294 @@ fn add_internally_tagged_enum(
     state.types.push('\n');
 }
 
-/// This follows serde's default approach of external tagging
-fn add_externally_tagged_enum(
+/// Conversion of Rust Enum to Typescript using adjacent tagging as per https://serde.rs/enum-representations.html
+/// where the tag and the content of each variant are kept in separate fields, e.g.
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(tag = "t", content = "c")]
+/// #[tsync]
+/// enum Block {
+///     Break,
+///     Para(Vec<Inline>),
+///     Str(String, u32),
+///     Heading { level: u8, text: String },
+/// }
+/// ```
+/// goes to `type Block = { "t": "Break" } | { "t": "Para", "c": Array<Inline> } | { "t": "Str", "c": [string, number] } | { "t": "Heading", "c": { level: number, text: string } }`
+/// (with each variant written as its own type, like internally tagged enums).
+fn add_adjacently_tagged_enum(
+    tag_name: String,
+    content_name: String,
     exported_struct: syn::ItemEnum,
     state: &mut BuildState,
-    casing: Option<Case>,
-    uses_type_interface: bool,
+    casing: Option<RenameRule>,
+    fields_casing: Option<RenameRule>,
+    config: &crate::BuildSettings,
 ) {
-    let export = if uses_type_interface { "" } else { "export " };
+    let export = if config.uses_type_interface { "" } else { "export " };
     let generics = utils::extract_struct_generics(exported_struct.generics.clone());
+    let generics_declaration =
+        utils::format_generics_declaration(&exported_struct.generics, state, config);
+    state.types.push_str(&format!(
+        "{export}type {interface_name}{generics_declaration} =",
+        interface_name = exported_struct.ident,
+    ));
+
+    let (untagged_variants, variants): (Vec<_>, Vec<_>) = exported_struct
+        .variants
+        .into_iter()
+        .partition(is_untagged_variant);
+
+    let variant_generics_list = variants
+        .iter()
+        .map(|variant| get_variant_generics(variant, &generics))
+        .collect::<Vec<_>>();
+
+    for (variant, variant_generics) in variants.iter().zip(&variant_generics_list) {
+        state.types.push_str(&format!(
+            "\n  | {interface_name}__{variant_name}{generics}",
+            interface_name = exported_struct.ident,
+            variant_name = variant.ident.unraw(),
+            generics = utils::format_generics(variant_generics)
+        ));
+    }
+    for variant in untagged_variants {
+        add_untagged_variant(variant, state, fields_casing);
+    }
+    state.types.push_str(";\n");
+
+    for (variant, variant_generics) in variants.into_iter().zip(variant_generics_list) {
+        state.types.push('\n');
+        let comments = utils::get_comments(variant.attrs.clone());
+        state.write_comments(&comments, 0);
+        state.types.push_str(&format!(
+            "type {interface_name}__{variant_name}{generics} = {{\n",
+            interface_name = exported_struct.ident,
+            variant_name = variant.ident.unraw(),
+            generics = utils::format_generics(&variant_generics),
+        ));
+
+        let variant_fields_casing = get_fields_casing(&variant, fields_casing);
+
+        // add discriminant
+        let indent = utils::build_indentation(2);
+        state.types.push_str(&format!(
+            "{indent}\"{tag_name}\": {};\n",
+            get_variant_tag(&variant, casing),
+        ));
+
+        // add content, unit variants don't have any
+        match variant.fields {
+            syn::Fields::Unit => {}
+            syn::Fields::Unnamed(fields) => {
+                state
+                    .types
+                    .push_str(&format!("{indent}\"{content_name}\": "));
+                super::structs::process_tuple_fields(fields, state);
+                state.types.push_str(";\n");
+            }
+            syn::Fields::Named(_) => {
+                state
+                    .types
+                    .push_str(&format!("{indent}\"{content_name}\": {{\n"));
+                super::structs::process_fields(
+                    variant.fields,
+                    state,
+                    4,
+                    variant_fields_casing,
+                    true,
+                );
+                state.types.push_str(&format!("{indent}}};\n"));
+            }
+        }
+        state.types.push_str("};");
+    }
+    state.types.push('\n');
+}
+
+/// The generics of an enum which are used by the fields of one of its variants
+fn get_variant_generics(variant: &syn::Variant, generics: &[syn::Ident]) -> Vec<syn::Ident> {
+    generics
+        .iter()
+        .filter(|gen| {
+            variant
+                .fields
+                .iter()
+                .any(|field| utils::type_contains_ident(&field.ty, gen))
+        })
+        .cloned()
+        .collect()
+}
+
+/// The name of a variant as serialized by serde: either its `#[serde(rename = "...")]`
+/// or its ident with the enum's `rename_all` casing applied, unless it's renamed for
+/// typescript only with `#[tsync(rename = "...")]`
+fn get_variant_name(variant: &syn::Variant, casing: Option<RenameRule>) -> String {
+    if let Some(rename) = utils::get_attribute_arg("tsync", "rename", &variant.attrs) {
+        return rename;
+    }
+    if let Some(rename) = utils::get_serde_arg("rename", &variant.attrs) {
+        return rename;
+    }
+
+    match casing {
+        Some(casing) => casing.apply_to_variant(&variant.ident.unraw().to_string()),
+        None => variant.ident.unraw().to_string(),
+    }
+}
+
+/// The casing of the fields of a struct variant: its own `#[serde(rename_all = "...")]`
+/// or the enum's `rename_all_fields` casing
+fn get_fields_casing(
+    variant: &syn::Variant,
+    fields_casing: Option<RenameRule>,
+) -> Option<RenameRule> {
+    utils::get_serde_case("rename_all", &variant.attrs).or(fields_casing)
+}
+
+/// The type of the tag of a variant: the string literal of its name, or `string` for
+/// a `#[serde(other)]` variant since it's deserialized from any unknown tag
+fn get_variant_tag(variant: &syn::Variant, casing: Option<RenameRule>) -> String {
+    if utils::has_attribute_arg("serde", "other", &variant.attrs) {
+        "string".to_owned()
+    } else {
+        format!("\"{}\"", get_variant_name(variant, casing))
+    }
+}
+
+/// Whether a variant is marked with `#[serde(untagged)]`, in which case it's
+/// serialized without a tag even though the rest of the enum is tagged
+fn is_untagged_variant(variant: &syn::Variant) -> bool {
+    utils::has_attribute_arg("serde", "untagged", &variant.attrs)
+}
+
+/// This follows serde's default approach of external tagging:
+/// unit variants become their name as a string (`"Variant"`), newtype and tuple
+/// variants become `{ "Variant": T }` and `{ "Variant": [A, B] }`, and struct
+/// variants become `{ "Variant": { ... } }`
+fn add_externally_tagged_enum(
+    exported_struct: syn::ItemEnum,
+    state: &mut BuildState,
+    casing: Option<RenameRule>,
+    fields_casing: Option<RenameRule>,
+    config: &crate::BuildSettings,
+) {
+    let export = if config.uses_type_interface { "" } else { "export " };
+    let generics = utils::format_generics_declaration(&exported_struct.generics, state, config);
     state.types.push_str(&format!(
         "{export}type {interface_name}{generics} =",
         interface_name = exported_struct.ident,
-        generics = utils::format_generics(&generics)
     ));
 
     for variant in exported_struct.variants {
+        if is_untagged_variant(&variant) {
+            add_untagged_variant(variant, state, fields_casing);
+            continue;
+        }
+
         state.types.push('\n');
-        let comments = utils::get_comments(variant.attrs);
+        let comments = utils::get_comments(variant.attrs.clone());
         state.write_comments(&comments, 2);
-        let field_name = if let Some(casing) = casing {
-            variant.ident.unraw().to_string().to_case(casing)
-        } else {
-            variant.ident.unraw().to_string()
-        };
+        let field_name = get_variant_name(&variant, casing);
+        let variant_fields_casing = get_fields_casing(&variant, fields_casing);
 
-        if let syn::Fields::Unnamed(fields) = &variant.fields {
-            // add discriminant
-            state
-                .types
-                .push_str(&format!("  | {{ \"{}\": ", field_name));
-            super::structs::process_tuple_fields(fields.clone(), state);
-            state.types.push_str(" }");
-        } else {
-            // add discriminant
-            state.types.push_str(&format!(
-                "  | {{\n{}\"{}\": {{",
-                utils::build_indentation(6),
-                field_name,
-            ));
-            let prepend;
-
-            prepend = utils::build_indentation(6);
-            state.types.push('\n');
-            super::structs::process_fields(variant.fields, state, 8, casing, true);
-
-            state
-                .types
-                .push_str(&format!("{}}}\n{}}}", prepend, utils::build_indentation(4)));
+        match &variant.fields {
+            // unit variants are serialized as just their name
+            syn::Fields::Unit => {
+                state
+                    .types
+                    .push_str(&format!("  | {}", get_variant_tag(&variant, casing)));
+            }
+            syn::Fields::Unnamed(fields) => {
+                // add discriminant
+                state
+                    .types
+                    .push_str(&format!("  | {{ \"{}\": ", field_name));
+                super::structs::process_tuple_fields(fields.clone(), state);
+                state.types.push_str(" }");
+            }
+            syn::Fields::Named(_) => {
+                // add discriminant
+                state.types.push_str(&format!(
+                    "  | {{\n{}\"{}\": {{",
+                    utils::build_indentation(6),
+                    field_name,
+                ));
+                let prepend = utils::build_indentation(6);
+                state.types.push('\n');
+                super::structs::process_fields(
+                    variant.fields,
+                    state,
+                    8,
+                    variant_fields_casing,
+                    true,
+                );
+
+                state
+                    .types
+                    .push_str(&format!("{}}}\n{}}}", prepend, utils::build_indentation(4)));
+            }
         }
     }
     state.types.push_str(";\n");
 }
 
+/// Conversion of Rust Enum to Typescript without any tag, as serde does for `#[serde(untagged)]`:
+/// unit variants become `null`, newtype variants become their field, tuple variants
+/// become arrays like `[A, B]` and struct variants become objects
 fn add_untagged_tagged_enum(
     exported_struct: syn::ItemEnum,
     state: &mut BuildState,
-    casing: Option<Case>,
-    uses_type_interface: bool,
+    fields_casing: Option<RenameRule>,
+    config: &crate::BuildSettings,
 ) {
-    let export = if uses_type_interface { "" } else { "export " };
-    let generics = utils::extract_struct_generics(exported_struct.generics.clone());
+    let export = if config.uses_type_interface { "" } else { "export " };
+    let generics = utils::format_generics_declaration(&exported_struct.generics, state, config);
 
     // Write type name and generics
     state.types.push_str(&format!(
         "{export}type {interface_name}{generics} =",
         interface_name = exported_struct.ident,
-        generics = utils::format_generics(&generics)
     ));
 
     // Loop over each variant of the enum
     for variant in exported_struct.variants {
-        state.types.push('\n');
-        // Copy comments from rust
-        let comments = utils::get_comments(variant.attrs);
-        state.write_comments(&comments, 2);
+        add_untagged_variant(variant, state, fields_casing);
+    }
+    state.types.push_str(";\n");
+}
 
-        // Unnamed fields:
+/// Write a variant as a member of a union without any tag, like serde does for
+/// untagged enums and for variants marked with `#[serde(untagged)]`
+fn add_untagged_variant(
+    variant: syn::Variant,
+    state: &mut BuildState,
+    fields_casing: Option<RenameRule>,
+) {
+    let casing = get_fields_casing(&variant, fields_casing);
+    state.types.push('\n');
+    // Copy comments from rust
+    let comments = utils::get_comments(variant.attrs);
+    state.write_comments(&comments, 2);
+
+    match variant.fields {
+        // Unit variants are serialized as null:
         // ```rs
         // enum Data {
-        //     Value1(i32)
+        //     Value1
         // }
         // ```
-        if let syn::Fields::Unnamed(fields) = &variant.fields {
-            // add discriminant
-            state.types.push_str(&format!("  | "));
-            super::structs::process_tuple_fields(fields.clone(), state);
-            state.types.push_str("");
+        syn::Fields::Unit => state.types.push_str("  | null"),
+        // Unnamed fields, newtypes are serialized as their field and tuples as arrays:
+        // ```rs
+        // enum Data {
+        //     Value1(i32),
+        //     Value2(i32, String),
+        // }
+        // ```
+        syn::Fields::Unnamed(fields) => {
+            state.types.push_str("  | ");
+            super::structs::process_tuple_fields(fields, state);
         }
         // Named fields:
         // ```rs
@@ -444,16 +676,14 @@ fn add_untagged_tagged_enum(
         //     Value1 { v: i32 }
         // }
         // ```
-        else {
-            // add discriminant
-            state.types.push_str(&format!("  | {{\n"));
+        fields @ syn::Fields::Named(_) => {
+            state.types.push_str("  | {\n");
 
-            super::structs::process_fields(variant.fields, state, 6, casing, true);
+            super::structs::process_fields(fields, state, 6, casing, true);
 
             state
                 .types
                 .push_str(&format!("{}}}", utils::build_indentation(4)));
         }
     }
-    state.types.push_str(";\n");
 }
@@ -0,0 +1,290 @@
+use convert_case::{Case, Casing};
+use syn::ext::IdentExt;
+
+use crate::case::RenameRule;
+use crate::typescript::convert_type;
+use crate::{utils, BuildState};
+
+/// Wrapper types which are always unwrapped in the arguments and return types of functions
+static UNWRAPPED_TYPES: &[&str] = &["Result", "Json"];
+
+/// The module `invoke` is imported from by default, which is the one of Tauri v2
+pub(crate) static TAURI_INVOKE_MODULE: &str = "@tauri-apps/api/core";
+
+/// Argument types of Tauri commands which Tauri provides, rather than the caller
+static TAURI_INJECTED_TYPES: &[&str] =
+    &["State", "AppHandle", "Window", "WebviewWindow", "Webview"];
+
+impl super::ToTypescript for syn::ItemFn {
+    fn convert_to_ts(self, state: &mut BuildState, config: &crate::BuildSettings) {
+        let export = if config.uses_type_interface { "" } else { "export " };
+        state.types.push('\n');
+
+        let comments = utils::get_comments(self.attrs.clone());
+        state.write_comments(&comments, 0);
+
+        let name = get_fn_name(&self.sig, &self.attrs);
+
+        // functions can be given any typescript type with `#[tsync(type = "...")]`
+        if let Some(ts_type) = utils::get_attribute_arg("tsync", "type", &self.attrs) {
+            state
+                .types
+                .push_str(&format!("{export}type {name} = {ts_type}\n"));
+            return;
+        }
+
+        if config.enable_tauri_commands {
+            add_tauri_command(self, name, state, config);
+            return;
+        }
+
+        let generics = utils::format_generics_declaration(&self.sig.generics, state, config);
+        let args = get_fn_args(&self.sig, config);
+        let return_type = get_return_type(&self.sig, state, config);
+
+        state
+            .types
+            .push_str(&format!("{export}type {name} = {generics}("));
+        write_args(args, state, None);
+        state
+            .types
+            .push_str(&format!(") => Promise<{return_type}>\n"));
+    }
+}
+
+/// Write a function calling a Tauri command with `invoke`, taking the arguments of the
+/// command which Tauri doesn't provide itself. Since `.d.ts` files can't contain functions,
+/// they get the type of the function instead.
+///
+/// # Example
+///
+/// ```ignore
+/// #[tauri::command]
+/// async fn get_user(user_id: u32, state: State<'_, Db>) -> Result<User, Error>
+/// ```
+///
+/// should become
+///
+/// ```ignore
+/// export function getUser(userId: number): Promise<User> {
+///   return invoke<User>("get_user", { userId });
+/// }
+/// ```
+fn add_tauri_command(
+    item: syn::ItemFn,
+    name: String,
+    state: &mut BuildState,
+    config: &crate::BuildSettings,
+) {
+    // tauri expects the arguments in camelCase, unless the command says otherwise
+    let case = utils::parse_serde_case(utils::get_attribute_arg(
+        "command",
+        "rename_all",
+        &item.attrs,
+    ))
+    .unwrap_or(RenameRule::CamelCase);
+
+    let generics = utils::format_generics_declaration(&item.sig.generics, state, config);
+    let args = match get_fn_args(&item.sig, config) {
+        syn::Fields::Named(fields) => fields.named,
+        _ => Default::default(),
+    };
+    let args = args
+        .into_iter()
+        .filter(|arg| !is_tauri_injected_type(&arg.ty))
+        .collect::<Vec<_>>();
+    let return_type = get_return_type(&item.sig, state, config);
+
+    // the parameters are named like the keys Tauri expects, unless they're renamed
+    let args = args
+        .iter()
+        .map(|arg| {
+            let key = case.apply_to_field(&arg.ident.as_ref().unwrap().unraw().to_string());
+            let param = utils::get_attribute_arg("tsync", "rename", &arg.attrs)
+                .unwrap_or_else(|| key.clone());
+            (key, param, super::structs::get_field_type(arg, state))
+        })
+        .collect::<Vec<_>>();
+
+    // only the trailing parameters can be left out
+    let required_len = args
+        .iter()
+        .rposition(|(_, _, arg_type)| !arg_type.is_optional)
+        .map_or(0, |index| index + 1);
+
+    let mut params = Vec::new();
+    let mut keys = Vec::new();
+    for (index, (key, param, arg_type)) in args.into_iter().enumerate() {
+        if index < required_len {
+            params.push(format!("{param}: {}", arg_type.into_nullable(state)));
+        } else {
+            params.push(format!("{param}?: {}", arg_type.ts_type));
+        }
+        if key == param {
+            keys.push(key);
+        } else {
+            keys.push(format!("{key}: {param}"));
+        }
+    }
+
+    let command = item.sig.ident.unraw().to_string();
+    let invoke_args = if keys.is_empty() {
+        String::new()
+    } else {
+        format!(", {{ {} }}", keys.join(", "))
+    };
+
+    let params = params.join(", ");
+    if config.uses_type_interface {
+        state.types.push_str(&format!(
+            "type {name} = {generics}({params}) => Promise<{return_type}>\n"
+        ));
+        return;
+    }
+
+    state.uses_tauri_invoke = true;
+    state.types.push_str(&format!(
+        "export function {name}{generics}({params}): Promise<{return_type}> {{\n  return invoke<{return_type}>(\"{command}\"{invoke_args});\n}}\n"
+    ));
+}
+
+fn is_tauri_injected_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(reference) => is_tauri_injected_type(&reference.elem),
+        syn::Type::Path(path) => path.path.segments.last().is_some_and(|segment| {
+            TAURI_INJECTED_TYPES.contains(&segment.ident.to_string().as_str())
+        }),
+        _ => false,
+    }
+}
+
+/// Whether a function is exported without being marked with #[tsync], as a Tauri command
+pub(crate) fn is_selected_command(attrs: &[syn::Attribute], config: &crate::BuildSettings) -> bool {
+    config.enable_tauri_commands && config.enable_tauri_command_attribute && is_tauri_command(attrs)
+}
+
+/// Whether a function is a Tauri command, i.e. marked with `#[tauri::command]`
+fn is_tauri_command(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let segments = &attr.path().segments;
+        segments
+            .last()
+            .is_some_and(|segment| segment.ident == "command")
+            && (segments.len() == 1 || segments[0].ident == "tauri")
+    })
+}
+
+/// The typescript name of a function: its `#[tsync(rename = "...")]`, or its name in
+/// camelCase, e.g. `getUser` for `get_user`
+pub(crate) fn get_fn_name(sig: &syn::Signature, attrs: &[syn::Attribute]) -> String {
+    utils::get_attribute_arg("tsync", "rename", attrs)
+        .unwrap_or_else(|| sig.ident.unraw().to_string().to_case(Case::Camel))
+}
+
+/// Write the arguments of a function as a single `args` object, like
+/// `args: { id: number }`, or nothing if it doesn't take any
+pub(crate) fn write_args(args: syn::Fields, state: &mut BuildState, case: Option<RenameRule>) {
+    if args.is_empty() {
+        return;
+    }
+
+    state.types.push_str("args: {\n");
+    super::structs::process_fields(args, state, 2, case, false);
+    state.types.push('}');
+}
+
+/// The arguments of a function as the fields of an object, with the wrapper types of
+/// their types unwrapped and the self and `#[tsync(skip)]` arguments left out. Arguments
+/// written as a pattern, like `Json(user): Json<User>`, are named after their binding.
+pub(crate) fn get_fn_args(sig: &syn::Signature, config: &crate::BuildSettings) -> syn::Fields {
+    let named = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            syn::FnArg::Typed(arg) => Some(arg),
+            syn::FnArg::Receiver(_) => None,
+        })
+        .enumerate()
+        .map(|(index, arg)| syn::Field {
+            attrs: arg.attrs.clone(),
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(get_arg_ident(&arg.pat).unwrap_or_else(|| {
+                syn::Ident::new(&format!("arg{index}"), proc_macro2::Span::call_site())
+            })),
+            colon_token: Some(Default::default()),
+            ty: unwrap_type(&arg.ty, config).clone(),
+        })
+        .collect();
+
+    let mut args = syn::Fields::Named(syn::FieldsNamed {
+        brace_token: Default::default(),
+        named,
+    });
+    utils::remove_skipped_fields(&mut args);
+    args
+}
+
+/// The types the typescript signature of a function refers to: the types of its arguments
+/// (except the ones Tauri provides to commands) and its return type, with their wrapper
+/// types unwrapped
+pub(crate) fn get_signature_types(
+    sig: &syn::Signature,
+    config: &crate::BuildSettings,
+) -> Vec<syn::Type> {
+    let mut types = get_fn_args(sig, config)
+        .into_iter()
+        .filter(|arg| !utils::has_attribute_arg("tsync", "type", &arg.attrs))
+        .filter(|arg| !config.enable_tauri_commands || !is_tauri_injected_type(&arg.ty))
+        .map(|arg| arg.ty)
+        .collect::<Vec<_>>();
+    if let syn::ReturnType::Type(_, ty) = &sig.output {
+        types.push(unwrap_type(ty, config).clone());
+    }
+    types
+}
+
+/// The typescript type a function resolves to, `void` if it doesn't return anything
+pub(crate) fn get_return_type(
+    sig: &syn::Signature,
+    state: &mut BuildState,
+    config: &crate::BuildSettings,
+) -> String {
+    let syn::ReturnType::Type(_, ty) = &sig.output else {
+        return "void".to_owned();
+    };
+
+    match unwrap_type(ty, config) {
+        syn::Type::Tuple(tuple) if tuple.elems.is_empty() => "void".to_owned(),
+        ty => convert_type(ty, state).into_nullable(state),
+    }
+}
+
+/// The ident bound by an argument pattern, like `id` in `id: u32`, `mut id: u32` or
+/// `Path(id): Path<u32>`
+fn get_arg_ident(pat: &syn::Pat) -> Option<syn::Ident> {
+    match pat {
+        syn::Pat::Ident(pat) => Some(pat.ident.clone()),
+        syn::Pat::TupleStruct(pat) if pat.elems.len() == 1 => get_arg_ident(&pat.elems[0]),
+        syn::Pat::Reference(pat) => get_arg_ident(&pat.pat),
+        _ => None,
+    }
+}
+
+/// Replace wrapper types like `Result<T, E>` or `Json<T>` by the type they wrap
+fn unwrap_type<'a>(ty: &'a syn::Type, config: &crate::BuildSettings) -> &'a syn::Type {
+    let syn::Type::Path(path) = ty else {
+        return ty;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return ty;
+    };
+
+    let ident = segment.ident.to_string();
+    let is_wrapper =
+        UNWRAPPED_TYPES.contains(&ident.as_str()) || config.unwrapped_types.contains(&ident);
+    match utils::get_type_args(ty).first() {
+        Some(inner) if is_wrapper => unwrap_type(inner, config),
+        _ => ty,
+    }
+}
@@ -1,8 +1,37 @@
 pub mod consts;
 pub mod enums;
+pub mod fns;
 pub mod structs;
 pub mod type_item;
 
 pub trait ToTypescript {
     fn convert_to_ts(self, state: &mut crate::BuildState, config: &crate::BuildSettings);
 }
+
+/// Write an item which is serialized as another type (e.g. `#[serde(transparent)]` structs)
+/// as an alias of that type, keeping the generics of the item
+pub(crate) fn add_type_alias(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    ty: &syn::Type,
+    state: &mut crate::BuildState,
+    config: &crate::BuildSettings,
+) {
+    let ty = crate::typescript::convert_type(ty, state).into_nullable(state);
+    add_ts_type_alias(ident, generics, &ty, state, config);
+}
+
+/// Write an alias of a typescript type named after an item, keeping the generics of the item
+pub(crate) fn add_ts_type_alias(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    ts_type: &str,
+    state: &mut crate::BuildState,
+    config: &crate::BuildSettings,
+) {
+    let export = if config.uses_type_interface { "" } else { "export " };
+    let generics = crate::utils::format_generics_declaration(generics, state, config);
+    state
+        .types
+        .push_str(&format!("{export}type {ident}{generics} = {ts_type}\n"));
+}
@@ -1,59 +1,78 @@
-use crate::typescript::convert_type;
+use crate::case::RenameRule;
+use crate::typescript::{convert_type, get_map_value_type, is_non_object_type, TsType};
 use crate::{utils, BuildState};
-use convert_case::{Case, Casing};
 use syn::ext::IdentExt;
 
 impl super::ToTypescript for syn::ItemStruct {
-    fn convert_to_ts(self, state: &mut BuildState, config: &crate::BuildSettings) {
+    fn convert_to_ts(mut self, state: &mut BuildState, config: &crate::BuildSettings) {
         let export = if config.uses_type_interface {
             ""
         } else {
             "export "
         };
-        let casing = utils::get_attribute_arg("serde", "rename_all", &self.attrs);
-        let casing = utils::parse_serde_case(casing);
+        let casing = utils::get_serde_case("rename_all", &self.attrs);
         state.types.push('\n');
 
         let comments = utils::get_comments(self.clone().attrs);
         state.write_comments(&comments, 0);
 
-        let intersections = get_intersections(&self.fields);
+        // items can be given any typescript type with `#[tsync(type = "...")]`
+        if let Some(ts_type) = utils::get_attribute_arg("tsync", "type", &self.attrs) {
+            super::add_ts_type_alias(&self.ident, &self.generics, &ts_type, state, config);
+            return;
+        }
 
-        let generics = utils::extract_struct_generics(self.generics.clone());
-        let generics = utils::format_generics(&generics);
+        utils::remove_skipped_fields(&mut self.fields);
 
-        match (
-            intersections,
-            matches!(self.fields, syn::Fields::Unnamed(_)),
-        ) {
-            (Some(intersections), false) => {
-                state.types.push_str(&format!(
-                    "{export}type {struct_name}{generics} = {intersections} & ",
-                    struct_name = self.ident,
-                    intersections = intersections
-                ));
-            }
-            (None, false) => {
-                state.types.push_str(&format!(
-                    "{export}interface {interface_name}{generics} ",
-                    interface_name = self.ident,
-                ));
+        // structs converted with `#[serde(into = "...")]` are serialized as that type
+        if let Some(ty) = utils::get_serde_conversion_type(&self.attrs) {
+            super::add_type_alias(&self.ident, &self.generics, &ty, state, config);
+            return;
+        }
+
+        // serde serializes unit structs (`struct Marker;`) as null
+        if let syn::Fields::Unit = self.fields {
+            state.types.push_str(&format!(
+                "{export}type {struct_name} = null\n",
+                struct_name = self.ident,
+            ));
+            return;
+        }
+
+        // transparent structs are serialized as their only field
+        if utils::has_attribute_arg("serde", "transparent", &self.attrs) {
+            if let Some(field) = utils::get_newtype_field(&self) {
+                super::add_type_alias(&self.ident, &self.generics, &field.ty, state, config);
+                return;
             }
-            (None, true) => {
-                state.types.push_str(&format!(
-                    "{export}type {struct_name}{generics} = ",
-                    struct_name = self.ident,
+        }
+
+        let generics = utils::format_generics_declaration(&self.generics, state, config);
+
+        if let syn::Fields::Unnamed(unnamed) = &self.fields {
+            // serde doesn't allow flattening the fields of tuple structs
+            if unnamed.unnamed.iter().any(is_flattened) {
+                state.diagnostics.push(format!(
+                    "#[serde(flatten)] was ignored on the fields of {}, since it can't be used on tuple structs",
+                    self.ident
                 ));
             }
-            (Some(_), true) => {
-                if crate::DEBUG.try_get().is_some_and(|d| *d) {
-                    println!(
-                        "#[tsync] failed for struct {}. cannot flatten fields of tuple struct",
-                        self.ident
-                    );
-                }
-                return;
-            }
+
+            state.types.push_str(&format!(
+                "{export}type {struct_name}{generics} = ",
+                struct_name = self.ident,
+            ));
+        } else if let Some(intersections) = get_intersections(&self.ident, &self.fields, state) {
+            state.types.push_str(&format!(
+                "{export}type {struct_name}{generics} = {intersections} & ",
+                struct_name = self.ident,
+                intersections = intersections
+            ));
+        } else {
+            state.types.push_str(&format!(
+                "{export}interface {interface_name}{generics} ",
+                interface_name = self.ident,
+            ));
         }
 
         if let syn::Fields::Unnamed(unnamed) = self.fields {
@@ -68,16 +87,16 @@ impl super::ToTypescript for syn::ItemStruct {
     }
 }
 
-static EMPTY_OBJECT_TYPE: &'static str = "[key: PropertyKey]: never;\n";
+static EMPTY_OBJECT_TYPE: &str = "[key: PropertyKey]: never;\n";
 
 /// # arguments
 ///
 /// - `use_empty_object_type` - if true, will use the empty object type as the type of the struct if it has no fields
-pub fn process_fields<'a>(
+pub fn process_fields(
     fields: syn::Fields,
     state: &mut BuildState,
     indentation_amount: i8,
-    case: impl Into<Option<Case<'a>>>,
+    case: impl Into<Option<RenameRule>>,
     use_empty_object_type: bool,
 ) {
     let space = utils::build_indentation(indentation_amount);
@@ -102,29 +121,58 @@ pub fn process_fields<'a>(
             continue;
         }
 
-        let comments = utils::get_comments(field.attrs);
+        let comments = utils::get_comments(field.attrs.clone());
 
         state.write_comments(&comments, 2);
-        let field_name = if let Some(name_case) = case {
-            field
-                .ident
-                .map(|id| id.unraw().to_string().to_case(name_case))
-                .unwrap()
+        let field_name = get_field_name(&field, case);
+
+        let field_type = get_field_type(&field, state);
+        // with null optionals, the field is always present but may be null
+        let optional_parameter_token = if (field_type.is_optional && !state.null_optionals)
+            || utils::has_attribute_arg("tsync", "optional", &field.attrs)
+        {
+            "?"
         } else {
-            field.ident.map(|i| i.unraw().to_string()).unwrap()
+            ""
+        };
+        let field_type = if state.null_optionals {
+            field_type.into_nullable(state)
+        } else {
+            field_type.ts_type
+        };
+        let readonly = if utils::has_attribute_arg("tsync", "readonly", &field.attrs) {
+            "readonly "
+        } else {
+            ""
         };
-
-        let field_type = convert_type(&field.ty);
         state.types.push_str(&format!(
-            "{space}{field_name}{optional_parameter_token}: {field_type};\n",
+            "{space}{readonly}{field_name}{optional_parameter_token}: {field_type};\n",
             space = space,
             field_name = field_name,
-            optional_parameter_token = if field_type.is_optional { "?" } else { "" },
-            field_type = field_type.ts_type
+            optional_parameter_token = optional_parameter_token,
+            field_type = field_type
         ));
     }
 }
 
+/// The name of a field as serialized by serde: either its `#[serde(rename = "...")]`
+/// or its ident with the container's `rename_all` casing applied, unless it's
+/// renamed for typescript only with `#[tsync(rename = "...")]`
+pub fn get_field_name(field: &syn::Field, case: Option<RenameRule>) -> String {
+    if let Some(rename) = utils::get_attribute_arg("tsync", "rename", &field.attrs) {
+        return rename;
+    }
+    if let Some(rename) = utils::get_serde_arg("rename", &field.attrs) {
+        return rename;
+    }
+
+    let ident = field.ident.as_ref().unwrap().unraw().to_string();
+    match case {
+        Some(case) => case.apply_to_field(&ident),
+        None => ident,
+    }
+}
+
 /// Process tuple fields
 ///
 /// NOTE: Currently, this function does not handle comments or attributes on tuple fields.
@@ -144,27 +192,59 @@ pub fn process_tuple_fields(fields: syn::FieldsUnnamed, state: &mut BuildState)
     let out = fields
         .unnamed
         .into_iter()
-        .map(|field| {
-            let field_type = convert_type(&field.ty);
-            field_type.ts_type
-        })
+        .map(|field| get_field_type(&field, state).into_nullable(state))
         .collect::<Vec<String>>();
 
     if out.len() == 1 {
         state.types.push_str(&out[0].to_string());
-    } else if !out.is_empty() {
-        state.types.push_str(&format!("[ {} ]", out.join(", ")));
+    } else if out.is_empty() {
+        state.types.push_str("[]");
+    } else {
+        state.types.push_str(&format!("[{}]", out.join(", ")));
     }
 }
 
-fn get_intersections(fields: &syn::Fields) -> Option<String> {
+/// The type of a field, with the overrides of its `#[tsync(type = "...")]`,
+/// `#[tsync(as = "...")]` and `#[tsync(nullable)]` arguments applied
+pub(crate) fn get_field_type(field: &syn::Field, state: &mut BuildState) -> TsType {
+    let mut field_type = convert_type(&field.ty, state);
+
+    if let Some(ts_type) = utils::get_attribute_arg("tsync", "type", &field.attrs) {
+        field_type.ts_type = ts_type;
+    }
+
+    // e.g. `as = "bigint"` turns `Array<number>` into `Array<bigint>`
+    if let Some(number_type) = utils::get_attribute_arg("tsync", "as", &field.attrs) {
+        field_type.ts_type = utils::replace_word(&field_type.ts_type, "number", &number_type);
+    }
+
+    if utils::has_attribute_arg("tsync", "nullable", &field.attrs) {
+        field_type.ts_type = format!("{} | null", field_type.ts_type);
+    }
+
+    field_type
+}
+
+fn is_flattened(field: &syn::Field) -> bool {
+    utils::has_attribute_arg("serde", "flatten", &field.attrs)
+}
+
+fn get_intersections(
+    struct_ident: &syn::Ident,
+    fields: &syn::Fields,
+    state: &mut BuildState,
+) -> Option<String> {
     let mut types = Vec::new();
+    let entry_types = get_entry_types(fields, state);
 
-    for field in fields {
-        let has_flatten_attr = utils::get_attribute_arg("serde", "flatten", &field.attrs).is_some();
-        let field_type = convert_type(&field.ty);
-        if has_flatten_attr {
-            types.push(field_type.ts_type);
+    for field in fields.iter().filter(|field| is_flattened(field)) {
+        match get_flattened_type(&field.ty, state, entry_types.as_deref()) {
+            Some(field_type) => types.push(field_type),
+            None => state.diagnostics.push(format!(
+                "field `{}` of {} was skipped: only structs, maps and enums can be flattened",
+                field.ident.as_ref().unwrap(),
+                struct_ident
+            )),
         }
     }
 
@@ -174,3 +254,74 @@ fn get_intersections(fields: &syn::Fields) -> Option<String> {
 
     Some(types.join(" & "))
 }
+
+/// The types of all the entries of a struct's object, which the index signatures of
+/// its flattened maps must allow: the types of its fields and of the values of its
+/// flattened maps, or None if it flattens other types, whose entries aren't known here
+fn get_entry_types(fields: &syn::Fields, state: &mut BuildState) -> Option<Vec<String>> {
+    let mut entry_types = Vec::new();
+    for field in fields {
+        let entry_type = if is_flattened(field) {
+            let ty = match utils::is_option_type(&field.ty) {
+                true => *utils::get_type_args(&field.ty).first()?,
+                false => &field.ty,
+            };
+            let value = get_map_value_type(ty, state)?;
+            convert_type(value, state).into_nullable(state)
+        } else {
+            get_field_type(field, state).into_nullable(state)
+        };
+        if !entry_types.contains(&entry_type) {
+            entry_types.push(entry_type);
+        }
+    }
+    Some(entry_types)
+}
+
+/// The type of the entries a flattened field adds to the object of its struct,
+/// or None if it isn't serialized as an object
+fn get_flattened_type(
+    ty: &syn::Type,
+    state: &mut BuildState,
+    entry_types: Option<&[String]>,
+) -> Option<String> {
+    if is_non_object_type(ty, state) {
+        return None;
+    }
+
+    // the entries of an optional field may be missing altogether
+    if utils::is_option_type(ty) {
+        let inner = *utils::get_type_args(ty).first()?;
+        return Some(format!(
+            "Partial<{}>",
+            get_flattened_type(inner, state, entry_types)?
+        ));
+    }
+
+    // the keys of maps are always serialized as strings, and the index signature
+    // must allow the other entries of the object too
+    if let Some(value) = get_map_value_type(ty, state) {
+        let value = convert_type(value, state).into_nullable(state);
+        let value = match entry_types {
+            Some(entry_types) => std::iter::once(&value)
+                .chain(
+                    entry_types
+                        .iter()
+                        .filter(|entry_type| **entry_type != value),
+                )
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" | "),
+            None => "unknown".to_owned(),
+        };
+        return Some(format!("{{ [key: string]: {value} }}"));
+    }
+
+    // unions (e.g. of the variants of a `Result`) need parentheses in an intersection
+    let field_type = convert_type(ty, state).ts_type;
+    if field_type.contains(" | ") {
+        Some(format!("({field_type})"))
+    } else {
+        Some(field_type)
+    }
+}
@@ -6,12 +6,16 @@ impl super::ToTypescript for syn::ItemType {
         let export = if config.uses_type_interface { "" } else { "export " };
         state.types.push('\n');
         let name = self.ident.unraw().to_string();
-        let ty = crate::typescript::convert_type(&self.ty);
+        let generics = crate::utils::format_generics_declaration(&self.generics, state, config);
+        let ty = match crate::utils::get_attribute_arg("tsync", "type", &self.attrs) {
+            Some(ts_type) => ts_type,
+            None => crate::typescript::convert_type(&self.ty, state).into_nullable(state),
+        };
         let comments = crate::utils::get_comments(self.attrs);
         state.write_comments(&comments, 0);
-        state
-            .types
-            .push_str(format!("{export}type {name} = {ty}", name = name, ty = ty.ts_type).as_str());
+        state.types.push_str(
+            format!("{export}type {name}{generics} = {ty}", name = name, ty = ty).as_str(),
+        );
 
         state.types.push('\n');
     }
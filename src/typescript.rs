@@ -1,7 +1,45 @@
+use quote::ToTokens;
+
+use crate::BuildState;
+
+/// Name of the recursive type which `serde_json::Value` is mapped to
+pub const JSON_VALUE_TYPE_NAME: &str = "JsonValue";
+
+/// Definition of the `JsonValue` type, written once to the output when any
+/// converted type refers to it.
+pub fn json_value_type_definition(export: &str) -> String {
+    format!(
+        "{export}type {name} =\n  | string\n  | number\n  | boolean\n  | null\n  | Array<{name}>\n  | {{ [key: string]: {name} }};\n",
+        name = JSON_VALUE_TYPE_NAME
+    )
+}
+
 #[derive(Debug)]
 pub struct TsType {
     pub ts_type: String,
     pub is_optional: bool,
+    /// whether the type is an alias of an `Option`, like `MaybeUser`, whose
+    /// typescript type already includes the `None` case
+    pub is_optional_alias: bool,
+}
+
+impl TsType {
+    /// Render the type for a position which can't be marked as optional (e.g. an
+    /// array element or a type alias), where optional types are written as a union
+    /// with `undefined`, or with `null` if `enable_null_optionals` is set.
+    pub fn into_nullable(self, state: &BuildState) -> String {
+        match self {
+            // unless the type already is null, like `Option<()>`
+            TsType {
+                is_optional: true,
+                is_optional_alias: false,
+                ts_type,
+            } if ts_type != state.optional_type() => {
+                format!("{} | {}", ts_type, state.optional_type())
+            }
+            TsType { ts_type, .. } => ts_type,
+        }
+    }
 }
 
 impl From<String> for TsType {
@@ -9,13 +47,26 @@ impl From<String> for TsType {
         Self {
             ts_type,
             is_optional: false,
+            is_optional_alias: false,
         }
     }
 }
 
-fn convert_generic(gen_ty: &syn::GenericArgument) -> TsType {
+fn convert_generic(gen_ty: &syn::GenericArgument, state: &mut BuildState) -> TsType {
     match gen_ty {
-        syn::GenericArgument::Type(ty) => convert_type(ty),
+        syn::GenericArgument::Type(ty) => convert_type(ty, state),
+        // const generic arguments become literal types, e.g. `Buffer<16>` becomes `Buffer<16>`
+        syn::GenericArgument::Const(syn::Expr::Lit(lit)) => {
+            lit.to_token_stream().to_string().into()
+        }
+        syn::GenericArgument::Const(syn::Expr::Block(block)) => {
+            match block.block.stmts.as_slice() {
+                [syn::Stmt::Expr(expr @ (syn::Expr::Lit(_) | syn::Expr::Path(_)), None)] => {
+                    expr.to_token_stream().to_string().into()
+                }
+                _ => "unknown".to_string().into(),
+            }
+        }
         _ => "unknown".to_string().into(),
     }
 }
@@ -49,7 +100,285 @@ fn try_match_ident_str(ident: &str) -> Result<String, ()> {
 }
 
 /// Returns Err(()) when no match is found
-fn try_match_with_args(ident: &str, args: &syn::PathArguments) -> Result<TsType, ()> {
+///
+/// Matches `serde_json::Value`, `serde_json::Map` and `serde_json::value::RawValue`
+/// and marks the shared `JsonValue` type as used so it gets written to the output.
+fn try_match_json_value(full_path: &[String], state: &mut BuildState) -> Result<TsType, ()> {
+    if !is_in_crate(full_path, &["serde_json"]) {
+        return Err(());
+    }
+
+    let ts_type = match full_path.last().unwrap().as_str() {
+        "Value" | "RawValue" => JSON_VALUE_TYPE_NAME.to_owned(),
+        "Map" => format!("Record<string, {}>", JSON_VALUE_TYPE_NAME),
+        _ => return Err(()),
+    };
+    state.uses_json_value = true;
+
+    Ok(ts_type.into())
+}
+
+/// The crates which may define each of the idents handled by `try_match_ident_str`
+/// and `try_match_with_args`. A path to an item defined in the input is only converted
+/// with these mappings if it goes through one of the listed crates, so `crate::DateTime`
+/// isn't mistaken for chrono's `DateTime`.
+static MAPPING_CRATES: &[(&str, &[&str])] = &[
+    ("String", &["std", "alloc"]),
+    ("NaiveDateTime", &["chrono"]),
+    ("DateTime", &["chrono"]),
+    ("Uuid", &["uuid"]),
+    ("Cow", &["std", "alloc"]),
+    ("Option", &["std", "core"]),
+    ("Box", &["std", "alloc"]),
+    ("Vec", &["std", "alloc"]),
+    ("HashMap", &["std", "hashbrown"]),
+    ("Result", &["std", "core"]),
+];
+
+/// Whether a resolved path may refer to one of the built-in mappings. Paths that
+/// couldn't be resolved to anything more than their ident (e.g. prelude types or
+/// glob imports) and paths to items of other crates, which may re-export the mapped
+/// types (e.g. `sqlx::types::Uuid`), are matched by name only.
+fn is_mapping_path(full_path: &[String], state: &BuildState) -> bool {
+    if full_path.len() == 1 {
+        return true;
+    }
+
+    let ident = full_path.last().unwrap();
+    let crates: &[&str] = MAPPING_CRATES
+        .iter()
+        .find(|(name, _)| name == ident)
+        .map_or(&["std", "core"], |(_, crates)| crates);
+
+    is_in_crate(full_path, crates) || find_declared_item(full_path, state).is_none()
+}
+
+/// Whether any of the modules of a resolved path is one of the given crates
+fn is_in_crate(full_path: &[String], crates: &[&str]) -> bool {
+    full_path[..full_path.len() - 1]
+        .iter()
+        .any(|module| crates.contains(&module.as_str()))
+}
+
+/// Whether a type is converted to something typescript knows about, i.e. a
+/// built-in mapping or a #[tsync] item, rather than passed through by name.
+pub fn is_known_type(ty: &syn::Type, state: &BuildState) -> bool {
+    let syn::Type::Path(p) = ty else {
+        return false;
+    };
+    let full_path = resolve_path(&p.path, state);
+
+    find_tsync_item(&full_path, state).is_some() || is_builtin_path(&full_path, state)
+}
+
+/// Whether a resolved path is converted with one of the built-in mappings, e.g.
+/// `Vec`, `chrono::DateTime` or `serde_json::Value`, rather than to an item of the input
+pub(crate) fn is_builtin_path(full_path: &[String], state: &BuildState) -> bool {
+    let ident = full_path.last().unwrap();
+
+    is_in_crate(full_path, &["serde_json"])
+        || (is_mapping_path(full_path, state)
+            && (try_match_ident_str(ident).is_ok()
+                || MAPPING_CRATES.iter().any(|(name, _)| name == ident)))
+}
+
+/// Resolve a path as written in the source to the full path it refers to, using
+/// the `use` items of the current file and the module path of the current file.
+/// Paths to items of the current crate start with `crate`.
+///
+/// # Example
+///
+/// ```ignore
+/// // in src/models/user.rs
+/// use chrono::DateTime;
+/// use super::v1;
+///
+/// struct User {
+///     created_at: DateTime<Utc>, // resolves to chrono::DateTime
+///     previous: v1::User,        // resolves to crate::models::v1::User
+///     role: Role,                // resolves to crate::models::user::Role if defined in this file
+/// }
+/// ```
+pub fn resolve_path(path: &syn::Path, state: &BuildState) -> Vec<String> {
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+
+    let first = segments[0].as_str();
+    if segments.len() == 1 && state.type_params.iter().any(|param| param == first) {
+        return segments;
+    }
+
+    let full_path = if path.leading_colon.is_some() {
+        segments
+    } else if let Some(import) = state.imports.get(first) {
+        import
+            .iter()
+            .cloned()
+            .chain(segments[1..].iter().cloned())
+            .collect()
+    } else if state.declared_items.contains(
+        &state
+            .module_path
+            .iter()
+            .chain(Some(&segments[0]))
+            .cloned()
+            .collect::<Vec<_>>(),
+    ) {
+        state.module_path.iter().cloned().chain(segments).collect()
+    } else {
+        segments
+    };
+
+    normalize_relative_path(full_path, &state.module_path)
+}
+
+/// Replace leading `self` and `super` segments with the module path they refer to.
+fn normalize_relative_path(full_path: Vec<String>, module_path: &[String]) -> Vec<String> {
+    match full_path[0].as_str() {
+        "self" => module_path
+            .iter()
+            .cloned()
+            .chain(full_path.into_iter().skip(1))
+            .collect(),
+        "super" => {
+            let supers = full_path.iter().take_while(|s| *s == "super").count();
+            let parent = &module_path[..module_path.len().saturating_sub(supers).max(1)];
+            parent
+                .iter()
+                .cloned()
+                .chain(full_path.into_iter().skip(supers))
+                .collect()
+        }
+        _ => full_path,
+    }
+}
+
+/// Find which of the given item paths a resolved path refers to.
+///
+/// The module paths of the indexed items are derived from the location of their
+/// file relative to the input directory, which may be a subdirectory of the crate,
+/// so an item matches if its module path is a suffix of the resolved path.
+fn find_item_path<'a, I>(full_path: &[String], item_paths: I) -> Option<&'a Vec<String>>
+where
+    I: Iterator<Item = &'a Vec<String>> + Clone,
+{
+    let tail = match full_path[0].as_str() {
+        "crate" => &full_path[1..],
+        _ => full_path,
+    };
+
+    item_paths
+        .clone()
+        .filter(|item_path| tail.ends_with(&item_path[1..]))
+        .max_by_key(|item_path| item_path.len())
+        .or_else(|| {
+            // unresolved idents (e.g. glob imports) are matched by name
+            if tail.len() != 1 {
+                return None;
+            }
+            item_paths
+                .clone()
+                .find(|item_path| item_path.last() == tail.last())
+        })
+}
+
+/// Find the typescript name of the `#[tsync]` item a resolved path refers to.
+pub fn find_tsync_item<'a>(full_path: &[String], state: &'a BuildState) -> Option<&'a str> {
+    let item_path = find_item_path(full_path, state.tsync_items.iter().map(|(path, _)| path))?;
+
+    state
+        .tsync_items
+        .iter()
+        .find(|(path, _)| path == item_path)
+        .map(|(_, ts_name)| ts_name.as_str())
+}
+
+/// Find the struct, enum or type alias defined in the input a resolved path refers to.
+pub fn find_declared_item<'a>(
+    full_path: &[String],
+    state: &'a BuildState,
+) -> Option<&'a Vec<String>> {
+    find_item_path(full_path, state.declared_items.iter())
+}
+
+/// Whether a resolved path refers to a type alias or newtype of an `Option`, like `type MaybeUser = Option<User>`
+fn is_optional_alias(full_path: &[String], state: &BuildState) -> bool {
+    find_item_path(full_path, state.optional_aliases.iter()).is_some()
+}
+
+/// Whether a type is known to be serialized as something other than an object or
+/// null, like a primitive, a sequence or a tuple struct. Types that can't be told
+/// apart (e.g. from other crates) are assumed to be objects.
+pub fn is_non_object_type(ty: &syn::Type, state: &BuildState) -> bool {
+    match ty {
+        syn::Type::Reference(p) => is_non_object_type(&p.elem, state),
+        syn::Type::Paren(p) => is_non_object_type(&p.elem, state),
+        syn::Type::Array(_) | syn::Type::Slice(_) => true,
+        syn::Type::Tuple(t) => !t.elems.is_empty(),
+        syn::Type::Path(p) => {
+            let full_path = resolve_path(&p.path, state);
+            let ident = full_path.last().unwrap().as_str();
+
+            if is_mapping_path(&full_path, state) {
+                if try_match_ident_str(ident).is_ok() {
+                    return true;
+                }
+                match (ident, &p.path.segments.last().unwrap().arguments) {
+                    ("Vec", _) => return true,
+                    ("Option" | "Box" | "Cow", syn::PathArguments::AngleBracketed(args)) => {
+                        return args.args.iter().any(|arg| match arg {
+                            syn::GenericArgument::Type(ty) => is_non_object_type(ty, state),
+                            _ => false,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+
+            find_item_path(&full_path, state.non_object_items.iter()).is_some()
+        }
+        _ => false,
+    }
+}
+
+/// Whether a type is serialized as null, like `()` or a unit struct
+pub fn is_unit_type(ty: &syn::Type, state: &BuildState) -> bool {
+    match ty {
+        syn::Type::Reference(p) => is_unit_type(&p.elem, state),
+        syn::Type::Paren(p) => is_unit_type(&p.elem, state),
+        syn::Type::Tuple(t) => t.elems.is_empty(),
+        syn::Type::Path(p) => {
+            let full_path = resolve_path(&p.path, state);
+            find_item_path(&full_path, state.unit_items.iter()).is_some()
+        }
+        _ => false,
+    }
+}
+
+/// The value type of a map, like `V` for `HashMap<K, V>` or `BTreeMap<K, V>`
+pub fn get_map_value_type<'a>(ty: &'a syn::Type, state: &BuildState) -> Option<&'a syn::Type> {
+    let syn::Type::Path(p) = ty else {
+        return None;
+    };
+    let full_path = resolve_path(&p.path, state);
+    let ident = full_path.last().unwrap();
+
+    if !is_mapping_path(&full_path, state) || !["HashMap", "BTreeMap"].contains(&ident.as_str()) {
+        return None;
+    }
+
+    crate::utils::get_type_args(ty).get(1).copied()
+}
+
+/// Returns Err(()) when no match is found
+fn try_match_with_args(
+    ident: &str,
+    args: &syn::PathArguments,
+    state: &mut BuildState,
+) -> Result<TsType, ()> {
     match ident {
         "Cow" => Ok(match &args {
             syn::PathArguments::AngleBracketed(angle_bracketed_argument) => {
@@ -61,30 +390,32 @@ fn try_match_with_args(ident: &str, args: &syn::PathArguments) -> Result<TsType,
                     return Ok("unknown".to_owned().into());
                 };
 
-                convert_generic(arg).ts_type.into()
+                convert_generic(arg, state).ts_type.into()
             }
             _ => "unknown".to_owned().into(),
         }),
         "Option" => Ok(TsType {
             is_optional: true,
+            is_optional_alias: false,
             ts_type: match &args {
                 syn::PathArguments::Parenthesized(parenthesized_argument) => {
                     format!("{:?}", parenthesized_argument)
                 }
                 syn::PathArguments::AngleBracketed(angle_bracketed_argument) => {
-                    convert_generic(angle_bracketed_argument.args.first().unwrap()).ts_type
+                    convert_generic(angle_bracketed_argument.args.first().unwrap(), state).ts_type
                 }
                 _ => "unknown".to_owned(),
             },
         }),
         "Box" => Ok(TsType {
             is_optional: false,
+            is_optional_alias: false,
             ts_type: match &args {
                 syn::PathArguments::Parenthesized(parenthesized_argument) => {
                     format!("{:?}", parenthesized_argument)
                 }
                 syn::PathArguments::AngleBracketed(angle_bracketed_argument) => {
-                    convert_generic(angle_bracketed_argument.args.first().unwrap()).ts_type
+                    convert_generic(angle_bracketed_argument.args.first().unwrap(), state).ts_type
                 }
                 _ => "unknown".to_owned(),
             },
@@ -95,20 +426,31 @@ fn try_match_with_args(ident: &str, args: &syn::PathArguments) -> Result<TsType,
             }
             syn::PathArguments::AngleBracketed(angle_bracketed_argument) => format!(
                 "Array<{}>",
-                match convert_generic(angle_bracketed_argument.args.first().unwrap()) {
-                    TsType {
-                        is_optional: true,
-                        ts_type,
-                    } => format!("{} | undefined", ts_type),
-                    TsType {
-                        is_optional: false,
-                        ts_type,
-                    } => ts_type,
-                }
+                convert_generic(angle_bracketed_argument.args.first().unwrap(), state)
+                    .into_nullable(state)
             )
             .into(),
             _ => "unknown".to_owned().into(),
         }),
+        // serde serializes results like an externally tagged enum
+        "Result" => Ok(match &args {
+            syn::PathArguments::AngleBracketed(angle_bracketed_argument) => {
+                let mut variants = ["Ok", "Err"]
+                    .iter()
+                    .zip(angle_bracketed_argument.args.iter())
+                    .map(|(variant, arg)| {
+                        let ts_type = convert_generic(arg, state).into_nullable(state);
+                        format!("{{ {}: {} }}", variant, ts_type)
+                    })
+                    .collect::<Vec<String>>();
+                // `io::Result<T>`-like aliases only name the ok type
+                if variants.len() == 1 {
+                    variants.push("{ Err: unknown }".to_owned());
+                }
+                variants.join(" | ").into()
+            }
+            _ => "unknown".to_owned().into(),
+        }),
         "HashMap" => Ok(match &args {
             syn::PathArguments::Parenthesized(parenthesized_argument) => {
                 format!("{:?}", parenthesized_argument).into()
@@ -118,18 +460,7 @@ fn try_match_with_args(ident: &str, args: &syn::PathArguments) -> Result<TsType,
                 angle_bracketed_argument
                     .args
                     .iter()
-                    .map(|arg| {
-                        match convert_generic(arg) {
-                            TsType {
-                                is_optional: true,
-                                ts_type,
-                            } => format!("{} | undefined", ts_type),
-                            TsType {
-                                is_optional: false,
-                                ts_type,
-                            } => ts_type,
-                        }
-                    })
+                    .map(|arg| { convert_generic(arg, state).into_nullable(state) })
                     .collect::<Vec<String>>()
                     .join(", ")
             )
@@ -140,8 +471,11 @@ fn try_match_with_args(ident: &str, args: &syn::PathArguments) -> Result<TsType,
     }
 }
 
-pub fn extract_custom_type(segment: &syn::PathSegment) -> Result<TsType, ()> {
-    let ident = segment.ident.to_string();
+pub fn extract_custom_type(
+    segment: &syn::PathSegment,
+    ident: String,
+    state: &mut BuildState,
+) -> Result<TsType, ()> {
     let args = &segment.arguments;
 
     match args {
@@ -150,16 +484,7 @@ pub fn extract_custom_type(segment: &syn::PathSegment) -> Result<TsType, ()> {
             let args = angle_bracketed_argument
                 .args
                 .iter()
-                .map(|arg| match convert_generic(arg) {
-                    TsType {
-                        is_optional: true,
-                        ts_type,
-                    } => format!("{} | undefined", ts_type),
-                    TsType {
-                        is_optional: false,
-                        ts_type,
-                    } => ts_type,
-                })
+                .map(|arg| convert_generic(arg, state).into_nullable(state))
                 .collect::<Vec<String>>()
                 .join(", ");
 
@@ -171,42 +496,57 @@ pub fn extract_custom_type(segment: &syn::PathSegment) -> Result<TsType, ()> {
     }
 }
 
-pub fn convert_type(ty: &syn::Type) -> TsType {
+pub fn convert_type(ty: &syn::Type, state: &mut BuildState) -> TsType {
     match ty {
-        syn::Type::Reference(p) => convert_type(&p.elem),
+        syn::Type::Reference(p) => convert_type(&p.elem, state),
         syn::Type::Path(p) => {
+            let full_path = resolve_path(&p.path, state);
             let segment = p.path.segments.last().unwrap();
-            let identifier = segment.ident.to_string();
+            let identifier = full_path.last().unwrap().clone();
+            let is_mapping_path = is_mapping_path(&full_path, state);
 
-            if let Ok(ts_type) = try_match_ident_str(&identifier) {
-                ts_type.into()
-            } else if let Ok(ts_type) = try_match_with_args(&identifier, &segment.arguments) {
+            if let Ok(ts_type) = try_match_json_value(&full_path, state) {
                 ts_type
-            } else if let Ok(ts_type) = extract_custom_type(segment) {
+            } else if let Some(ts_type) = is_mapping_path
+                .then(|| try_match_ident_str(&identifier).ok())
+                .flatten()
+            {
+                ts_type.into()
+            } else if let Some(ts_type) = is_mapping_path
+                .then(|| try_match_with_args(&identifier, &segment.arguments, state).ok())
+                .flatten()
+            {
                 ts_type
             } else {
-                "unknown".to_owned().into()
+                let ident = find_tsync_item(&full_path, state)
+                    .map_or(identifier, |ts_name| ts_name.to_owned());
+                let is_optional = is_optional_alias(&full_path, state);
+                match extract_custom_type(segment, ident, state) {
+                    Ok(ts_type) => TsType {
+                        is_optional,
+                        is_optional_alias: is_optional,
+                        ts_type: ts_type.ts_type,
+                    },
+                    Err(_) => "unknown".to_owned().into(),
+                }
             }
         }
+        syn::Type::Paren(p) => convert_type(&p.elem, state),
+        syn::Type::Array(a) => format!("Array<{}>", convert_type(&a.elem, state).ts_type).into(),
+        syn::Type::Slice(s) => format!("Array<{}>", convert_type(&s.elem, state).ts_type).into(),
+        // serde serializes `()` as null
+        syn::Type::Tuple(t) if t.elems.is_empty() => "null".to_owned().into(),
+        // a value of type `!` can never be serialized
+        syn::Type::Never(_) => "never".to_owned().into(),
         syn::Type::Tuple(t) => {
             let types = t
                 .elems
                 .iter()
-                .map(convert_type)
-                .map(|ty| {
-                    if ty.is_optional {
-                        format!("{} | undefined", ty.ts_type)
-                    } else {
-                        ty.ts_type
-                    }
-                })
+                .map(|ty| convert_type(ty, state).into_nullable(state))
                 .collect::<Vec<String>>()
                 .join(", ");
 
-            TsType {
-                ts_type: format!("[{types}]"),
-                is_optional: false,
-            }
+            format!("[{types}]").into()
         }
 
         _ => "unknown".to_owned().into(),
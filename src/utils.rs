@@ -1,18 +1,13 @@
+use std::collections::HashMap;
+
 use quote::ToTokens;
 use syn::parse::Parser;
 use syn::punctuated::Punctuated;
 use syn::{Expr, ExprPath, MetaNameValue, Token};
 
-pub(crate) static RENAME_RULES: &[(&str, convert_case::Case<'static>)] = &[
-    ("lowercase", convert_case::Case::Lower),
-    ("UPPERCASE", convert_case::Case::Upper),
-    ("PascalCase", convert_case::Case::Pascal),
-    ("camelCase", convert_case::Case::Camel),
-    ("snake_case", convert_case::Case::Snake),
-    ("SCREAMING_SNAKE_CASE", convert_case::Case::UpperSnake),
-    ("kebab-case", convert_case::Case::Kebab),
-    ("SCREAMING-KEBAB-CASE", convert_case::Case::UpperKebab),
-];
+use crate::case::RenameRule;
+use crate::typescript::{convert_type, is_known_type};
+use crate::{BuildSettings, BuildState};
 
 pub fn has_attribute(needle: &str, attributes: &[syn::Attribute]) -> bool {
     attributes.iter().any(|attr| {
@@ -81,23 +76,48 @@ fn check_token(token: proc_macro2::TokenTree, arg: &str) -> Option<String> {
             // Otherwise, check to see if the group is a `Expr` of `Punctuated<_, P>` attributes,
             // separated by `P`, `Token![,]` in this case.
             // (.e.g `default, skip_serializing`)
-            Parser::parse2(
+            if let Ok(comma_seperated_values) = Parser::parse2(
                 Punctuated::<Expr, Token![,]>::parse_terminated,
                 group.stream(),
-            )
-            // If the expression cannot be parsed, return None
-            .map_or(None, |comma_seperated_values| {
+            ) {
                 // Otherwise move the pairs into an iterator
-                comma_seperated_values
+                let found = comma_seperated_values
                     .into_iter()
                     // Checking each is a `ExprPath`, object, yielding elements while the method
                     // returns true.
                     .map_while(check_expression_is_path)
                     // Check if any yielded paths equal `arg`
-                    .any(|expr_path| expr_path.path.segments[0].ident.to_string().eq(arg))
-                    // If so, return `Some(arg)`, otherwise `None`.
-                    .then_some(arg.to_owned())
-            })
+                    .any(|expr_path| expr_path.path.segments[0].ident.to_string().eq(arg));
+                // If so, return `Some(arg)`
+                if found {
+                    return Some(arg.to_owned());
+                }
+            }
+
+            // Lastly, the group may mix both kinds or use keywords as names,
+            // (.e.g `untagged, rename = "other"` or `type = "string"`)
+            let mut value = None;
+            let parser = syn::meta::parser(|meta| {
+                if meta.input.peek(Token![=]) {
+                    let expr: Expr = meta.value()?.parse()?;
+                    if meta.path.is_ident(arg) {
+                        value = Some(match expr {
+                            Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Str(lit),
+                                ..
+                            }) => lit.value(),
+                            expr => expr.to_token_stream().to_string(),
+                        });
+                    }
+                } else if meta.input.peek(syn::token::Paren) {
+                    meta.input.parse::<proc_macro2::TokenTree>()?;
+                } else if meta.path.is_ident(arg) {
+                    value = Some(arg.to_owned());
+                }
+                Ok(())
+            });
+            Parser::parse2(parser, group.stream()).ok()?;
+            value
         }
     }
 }
@@ -108,15 +128,26 @@ fn check_token(token: proc_macro2::TokenTree, arg: &str) -> Option<String> {
 /// For #[derive(Serialize_repr)], get_attribute_arg("derive", "Serialize_repr", attributes) will
 /// return Some("Serialize_repr")
 pub fn get_attribute_arg(needle: &str, arg: &str, attributes: &[syn::Attribute]) -> Option<String> {
-    // check if attribute list contains the argument we are interested in
-    // TODO: don't use a for loop here or iterator here
-    get_attribute(needle, attributes).and_then(|attr| {
-        attr.meta
-            .to_token_stream()
-            .into_iter()
-            .filter_map(|token| check_token(token, arg))
-            .next()
-    })
+    // check if attribute list contains the argument we are interested in, the same
+    // attribute can be written several times (e.g. `#[serde(tag = "t")] #[serde(rename_all = "...")]`)
+    // so all of them are checked, starting from the last
+    attributes
+        .iter()
+        .rev()
+        .filter(|attr| {
+            attr.meta
+                .path()
+                .segments
+                .iter()
+                .any(|segment| segment.ident == needle)
+        })
+        .find_map(|attr| {
+            attr.meta
+                .to_token_stream()
+                .into_iter()
+                .filter_map(|token| check_token(token, arg))
+                .next()
+        })
 }
 
 /// Check has an attribute arg.
@@ -214,6 +245,204 @@ pub fn format_generics(generics: &[syn::Ident]) -> String {
     format!("<{}>", generics)
 }
 
+/// Format the generic parameters of an item for its typescript declaration.
+/// Unlike [`format_generics`], defaults are kept and translated to typescript
+/// default type arguments, e.g. `struct Page<T = String>` becomes `Page<T = string>`.
+///
+/// Const generics become type parameters constrained to the type of their value,
+/// e.g. `const N: usize` becomes `N extends number`, and with
+/// `enable_generic_constraints`, trait bounds are translated where possible
+/// (see [`get_generic_constraints`]).
+pub fn format_generics_declaration(
+    generics: &syn::Generics,
+    state: &mut BuildState,
+    config: &BuildSettings,
+) -> String {
+    let params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) => {
+                let mut declaration = param.ident.to_string();
+                if config.enable_generic_constraints {
+                    let constraints = get_generic_constraints(param, generics, state);
+                    if !constraints.is_empty() {
+                        declaration.push_str(&format!(" extends {}", constraints.join(" & ")));
+                    }
+                }
+                if let Some(default) = &param.default {
+                    declaration.push_str(&format!(" = {}", convert_type(default, state).ts_type));
+                }
+                Some(declaration)
+            }
+            syn::GenericParam::Const(param) => {
+                let mut declaration = format!(
+                    "{} extends {}",
+                    param.ident,
+                    convert_type(&param.ty, state).ts_type
+                );
+                if let Some(syn::Expr::Lit(default)) = &param.default {
+                    declaration.push_str(&format!(" = {}", default.to_token_stream()));
+                }
+                Some(declaration)
+            }
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    if params.is_empty() {
+        return String::new();
+    }
+
+    format!("<{}>", params.join(", "))
+}
+
+/// Traits whose type argument describes a type a generic parameter can stand in for
+static CONVERSION_TRAITS: &[&str] = &["Into", "AsRef", "Borrow"];
+
+/// Translate the trait bounds of a generic type parameter (both inline and in the
+/// `where` clause) to typescript constraints. Only conversion bounds to types
+/// which are known to typescript are translated, e.g. `T: Into<UserId>` gives
+/// `UserId` if `UserId` is a #[tsync] item; other bounds (like `T: Clone`) have
+/// no typescript equivalent and are left out.
+pub fn get_generic_constraints(
+    param: &syn::TypeParam,
+    generics: &syn::Generics,
+    state: &mut BuildState,
+) -> Vec<String> {
+    let where_bounds = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .filter_map(|predicate| match predicate {
+            syn::WherePredicate::Type(predicate) => match &predicate.bounded_ty {
+                syn::Type::Path(ty) if ty.path.is_ident(&param.ident) => Some(&predicate.bounds),
+                _ => None,
+            },
+            _ => None,
+        })
+        .flatten();
+
+    param
+        .bounds
+        .iter()
+        .chain(where_bounds)
+        .filter_map(|bound| {
+            let syn::TypeParamBound::Trait(trait_bound) = bound else {
+                return None;
+            };
+            let segment = trait_bound.path.segments.last()?;
+            if !CONVERSION_TRAITS.iter().any(|name| segment.ident == name) {
+                return None;
+            }
+            let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            match args.args.first()? {
+                syn::GenericArgument::Type(ty) if is_known_type(ty, state) => {
+                    Some(convert_type(ty, state).ts_type)
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Determine whether a type is an `Option`, i.e. `Option<T>` or `std::option::Option<T>`
+pub fn is_option_type(ty: &syn::Type) -> bool {
+    let syn::Type::Path(ty_path) = ty else {
+        return false;
+    };
+    let segments = &ty_path.path.segments;
+
+    segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Option")
+        && (segments.len() == 1 || segments[0].ident == "std" || segments[0].ident == "core")
+}
+
+/// The field a struct is serialized as, if it's serialized as its only field rather
+/// than as an object: newtype structs like `struct Id(u32)`, and `#[serde(transparent)]`
+/// structs which have a single field that isn't skipped.
+pub fn get_newtype_field(item: &syn::ItemStruct) -> Option<&syn::Field> {
+    let mut fields = match &item.fields {
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => return fields.unnamed.first(),
+        fields if has_attribute_arg("serde", "transparent", &item.attrs) => fields
+            .iter()
+            .filter(|field| !has_attribute_arg("serde", "skip", &field.attrs)),
+        _ => return None,
+    };
+
+    match (fields.next(), fields.next()) {
+        (Some(field), None) => Some(field),
+        _ => None,
+    }
+}
+
+/// The type arguments of the last segment of a type's path, e.g. `[K, V]` for `HashMap<K, V>`
+pub fn get_type_args(ty: &syn::Type) -> Vec<&syn::Type> {
+    let syn::Type::Path(ty_path) = ty else {
+        return Vec::new();
+    };
+    let Some(syn::PathArguments::AngleBracketed(args)) = ty_path
+        .path
+        .segments
+        .last()
+        .map(|segment| &segment.arguments)
+    else {
+        return Vec::new();
+    };
+
+    args.args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Remove the fields marked with `#[tsync(skip)]`
+pub fn remove_skipped_fields(fields: &mut syn::Fields) {
+    let is_kept = |field: &syn::Field| !has_attribute_arg("tsync", "skip", &field.attrs);
+    match fields {
+        syn::Fields::Named(fields) => {
+            fields.named = std::mem::take(&mut fields.named)
+                .into_iter()
+                .filter(is_kept)
+                .collect();
+        }
+        syn::Fields::Unnamed(fields) => {
+            fields.unnamed = std::mem::take(&mut fields.unnamed)
+                .into_iter()
+                .filter(is_kept)
+                .collect();
+        }
+        syn::Fields::Unit => {}
+    }
+}
+
+/// Replace the whole-word occurrences of `word` in a typescript type, e.g. `number`
+/// in `Array<number>` but not in `MyNumberish`
+pub fn replace_word(ts_type: &str, word: &str, replacement: &str) -> String {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let mut result = String::new();
+    let mut rest = ts_type;
+
+    while let Some(index) = rest.find(word) {
+        let before = rest[..index].chars().next_back();
+        let after = rest[index + word.len()..].chars().next();
+        let is_whole_word = !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char);
+
+        result.push_str(&rest[..index]);
+        result.push_str(if is_whole_word { replacement } else { word });
+        rest = &rest[index + word.len()..];
+    }
+    result.push_str(rest);
+
+    result
+}
+
 /// Determine whether a type contains the given ident.
 pub fn type_contains_ident(ty: &syn::Type, ident: &syn::Ident) -> bool {
     match ty {
@@ -263,13 +492,124 @@ pub fn get_attribute<'a>(
         })
 }
 
-pub(crate) fn parse_serde_case(
-    val: impl Into<Option<String>>,
-) -> Option<convert_case::Case<'static>> {
-    val.into().and_then(|x| {
-        RENAME_RULES
-            .iter()
-            .find(|(name, _)| name == &x)
-            .map(|(_, rule)| *rule)
-    })
+/// Get the value of a serde argument which may be given per direction, like
+/// `#[serde(rename = "name")]` or `#[serde(rename(serialize = "name", deserialize = "other"))]`.
+/// The typescript types describe serialized values, so the `serialize` value is used.
+pub(crate) fn get_serde_arg(arg: &str, attributes: &[syn::Attribute]) -> Option<String> {
+    if let Some(value) = get_attribute_arg("serde", arg, attributes) {
+        return Some(value);
+    }
+
+    attributes
+        .iter()
+        .rev()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .find_map(|attr| {
+            let syn::Meta::List(list) = &attr.meta else {
+                return None;
+            };
+            list.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+                .ok()?
+                .into_iter()
+                .find_map(|meta| match meta {
+                    syn::Meta::List(directions) if directions.path.is_ident(arg) => directions
+                        .parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)
+                        .ok()?
+                        .into_iter()
+                        .find(|nvp| nvp.path.is_ident("serialize"))
+                        .map(|nvp| {
+                            let value = nvp.value.to_token_stream().to_string();
+                            value[1..value.len() - 1].to_owned()
+                        }),
+                    _ => None,
+                })
+        })
+}
+
+/// The type an item is serialized as with `#[serde(into = "...")]`. Since `from` and
+/// `try_from` are only used when deserializing, they're only used for the items which
+/// don't derive `Serialize`, which are assumed to have the same shape both ways.
+pub fn get_serde_conversion_type(attributes: &[syn::Attribute]) -> Option<syn::Type> {
+    let args: &[&str] = if has_derive("Serialize", attributes) {
+        &["into"]
+    } else {
+        &["into", "from", "try_from"]
+    };
+    args.iter()
+        .find_map(|arg| get_serde_arg(arg, attributes))
+        .and_then(|ty| syn::parse_str(&ty).ok())
+}
+
+/// Whether an item derives the given trait, written with its path (`serde::Serialize`) or not
+pub(crate) fn has_derive(name: &str, attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            )
+            .ok()
+        })
+        .flatten()
+        .any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == name)
+        })
+}
+
+/// Get the casing of a `rename_all`-like serde argument, e.g. `get_serde_case("rename_all_fields", attributes)`
+pub(crate) fn get_serde_case(arg: &str, attributes: &[syn::Attribute]) -> Option<RenameRule> {
+    parse_serde_case(get_serde_arg(arg, attributes))
+}
+
+pub(crate) fn parse_serde_case(val: impl Into<Option<String>>) -> Option<RenameRule> {
+    val.into().and_then(|x| RenameRule::from_name(&x))
+}
+
+/// Flatten a `use` tree into a map from the name it binds in scope to the full
+/// path it refers to.
+///
+/// # Example
+///
+/// ```ignore
+/// use serde_json::{value::RawValue, Map as JsonMap};
+/// ```
+///
+/// binds `RawValue` to `serde_json::value::RawValue` and `JsonMap` to `serde_json::Map`.
+/// Glob imports don't bind any names and are ignored.
+pub fn collect_use_paths(
+    tree: &syn::UseTree,
+    prefix: &mut Vec<String>,
+    paths: &mut HashMap<String, Vec<String>>,
+) {
+    match tree {
+        syn::UseTree::Path(use_path) => {
+            prefix.push(use_path.ident.to_string());
+            collect_use_paths(&use_path.tree, prefix, paths);
+            prefix.pop();
+        }
+        syn::UseTree::Name(use_name) if use_name.ident == "self" => {
+            if let Some(last) = prefix.last() {
+                paths.insert(last.clone(), prefix.clone());
+            }
+        }
+        syn::UseTree::Name(use_name) => {
+            let mut full_path = prefix.clone();
+            full_path.push(use_name.ident.to_string());
+            paths.insert(use_name.ident.to_string(), full_path);
+        }
+        syn::UseTree::Rename(use_rename) => {
+            let mut full_path = prefix.clone();
+            full_path.push(use_rename.ident.to_string());
+            paths.insert(use_rename.rename.to_string(), full_path);
+        }
+        syn::UseTree::Group(use_group) => {
+            for tree in &use_group.items {
+                collect_use_paths(tree, prefix, paths);
+            }
+        }
+        syn::UseTree::Glob(_) => {}
+    }
 }
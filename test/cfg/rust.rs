@@ -0,0 +1,60 @@
+// evaluated with: --features ts,serde --target-os linux
+
+#[cfg(feature = "serde")]
+use chrono::DateTime;
+#[cfg(not(feature = "serde"))]
+use time::OffsetDateTime as DateTime;
+
+#[cfg_attr(feature = "ts", tsync)]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+struct Settings {
+    display_name: String,
+    #[cfg(feature = "admin")]
+    permissions: Vec<String>,
+    #[cfg(not(feature = "admin"))]
+    role: String,
+    #[cfg(all(unix, feature = "serde"))]
+    socket_path: String,
+    #[cfg(windows)]
+    pipe_name: String,
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    home_dir: String,
+}
+
+#[tsync]
+#[cfg(test)]
+struct TestOnly {
+    name: String,
+}
+
+#[tsync]
+#[cfg(not(test))]
+struct NotTest {
+    name: String,
+}
+
+#[tsync]
+enum Platform {
+    #[cfg(target_family = "unix")]
+    Unix,
+    #[cfg(target_family = "windows")]
+    Windows,
+    Tuple(u32, #[cfg(feature = "extra")] u32),
+}
+
+#[tsync]
+struct Session {
+    started_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "admin")]
+#[tsync]
+type AdminId = u64;
+
+#[cfg(test)]
+mod tests {
+    #[tsync]
+    struct Fixture {
+        name: String,
+    }
+}
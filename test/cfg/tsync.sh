@@ -0,0 +1,8 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+cargo run -- -i rust.rs -o typescript.d.ts --features ts,serde --target-os linux
+cargo run -- -i rust.rs -o typescript.ts --features ts,serde --target-os linux
@@ -0,0 +1,20 @@
+/* This file is generated and managed by tsync */
+
+interface Settings {
+  display_name: string;
+  role: string;
+  socket_path: string;
+  home_dir: string;
+}
+
+interface NotTest {
+  name: string;
+}
+
+type Platform =
+  | "Unix"
+  | { "Tuple": number };
+
+interface Session {
+  started_at: Date;
+}
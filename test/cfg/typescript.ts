@@ -0,0 +1,20 @@
+/* This file is generated and managed by tsync */
+
+export interface Settings {
+  display_name: string;
+  role: string;
+  socket_path: string;
+  home_dir: string;
+}
+
+export interface NotTest {
+  name: string;
+}
+
+export type Platform =
+  | "Unix"
+  | { "Tuple": number };
+
+export interface Session {
+  started_at: Date;
+}
@@ -0,0 +1,31 @@
+/// Exported when the ts feature is enabled
+#[cfg_attr(feature = "ts", tsync)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+struct Gated {
+    #[cfg_attr(feature = "serde", serde(rename = "ID"))]
+    id: u32,
+    display_name: String,
+    #[cfg_attr(feature = "ts", tsync(skip))]
+    secret: String,
+}
+
+#[cfg_attr(feature = "ts", derive(Debug), tsync)]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+enum Shape {
+    Circle { radius: f64 },
+    #[cfg_attr(all(feature = "serde", unix), cfg_attr(feature = "ts", serde(rename = "rect")))]
+    Rectangle { width: f64, height: f64 },
+}
+
+#[tsync::tsync]
+struct Qualified {
+    name: String,
+}
+
+#[tsync::tsync(rename = "Renamed")]
+type Alias = Qualified;
+
+#[cfg_attr(feature = "ts", allow(dead_code))]
+struct NotExported {
+    name: String,
+}
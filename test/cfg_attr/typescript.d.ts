@@ -0,0 +1,27 @@
+/* This file is generated and managed by tsync */
+
+/** Exported when the ts feature is enabled */
+interface Gated {
+  ID: number;
+  displayName: string;
+}
+
+type Shape =
+  | Shape__Circle
+  | Shape__Rectangle;
+
+type Shape__Circle = {
+  "type": "Circle";
+  radius: number;
+};
+type Shape__Rectangle = {
+  "type": "rect";
+  width: number;
+  height: number;
+};
+
+interface Qualified {
+  name: string;
+}
+
+type Renamed = Qualified
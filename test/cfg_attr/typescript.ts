@@ -0,0 +1,27 @@
+/* This file is generated and managed by tsync */
+
+/** Exported when the ts feature is enabled */
+export interface Gated {
+  ID: number;
+  displayName: string;
+}
+
+export type Shape =
+  | Shape__Circle
+  | Shape__Rectangle;
+
+type Shape__Circle = {
+  "type": "Circle";
+  radius: number;
+};
+type Shape__Rectangle = {
+  "type": "rect";
+  width: number;
+  height: number;
+};
+
+export interface Qualified {
+  name: string;
+}
+
+export type Renamed = Qualified
@@ -0,0 +1,7 @@
+use crate::schema::Schema;
+
+#[tsync]
+struct Route {
+    path: String,
+    schema: Schema,
+}
@@ -0,0 +1,4 @@
+#[tsync]
+pub struct Schema {
+    version: u32,
+}
@@ -0,0 +1,23 @@
+mod models;
+
+#[path = "generated/schema.rs"]
+mod schema;
+
+// inline modules are read in place, with their own imports
+mod api {
+    use crate::models::user::User;
+
+    #[tsync]
+    struct UserResponse {
+        user: User,
+        settings: super::Settings,
+    }
+
+    // declared in api/routes.rs
+    mod routes;
+}
+
+#[tsync]
+struct Settings {
+    theme: String,
+}
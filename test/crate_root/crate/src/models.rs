@@ -0,0 +1,6 @@
+pub mod user;
+
+#[tsync]
+struct Model {
+    id: u32,
+}
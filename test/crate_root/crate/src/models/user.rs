@@ -0,0 +1,5 @@
+#[tsync]
+pub struct User {
+    name: String,
+    model: super::Model,
+}
@@ -0,0 +1,6 @@
+// not declared with `mod orphan;`, so it isn't part of the crate
+
+#[tsync]
+struct Orphan {
+    name: String,
+}
@@ -0,0 +1,28 @@
+/* This file is generated and managed by tsync */
+
+interface UserResponse {
+  user: User;
+  settings: Settings;
+}
+
+interface Settings {
+  theme: string;
+}
+
+interface Model {
+  id: number;
+}
+
+interface User {
+  name: string;
+  model: Model;
+}
+
+interface Schema {
+  version: number;
+}
+
+interface Route {
+  path: string;
+  schema: Schema;
+}
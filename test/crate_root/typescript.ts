@@ -0,0 +1,28 @@
+/* This file is generated and managed by tsync */
+
+export interface UserResponse {
+  user: User;
+  settings: Settings;
+}
+
+export interface Settings {
+  theme: string;
+}
+
+export interface Model {
+  id: number;
+}
+
+export interface User {
+  name: string;
+  model: Model;
+}
+
+export interface Schema {
+  version: number;
+}
+
+export interface Route {
+  path: string;
+  schema: Schema;
+}
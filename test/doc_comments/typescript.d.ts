@@ -8,16 +8,17 @@ type EnumTest =
 
 /** enum property comment */
 type EnumTest__One = {
-  type: "ONE";
+  "type": "ONE";
 };
 /** enum tuple comment */
 type EnumTest__Two = {
-  type: "TWO"} & StructTest
+  "type": "TWO";
+} & StructTest;
 /** enum struct comment */
 type EnumTest__Three = {
-  type: "THREE";
+  "type": "THREE";
   /** enum struct property comment */
-  ID: string;
+  id: string;
 };
 
 /** struct comment */
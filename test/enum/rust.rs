@@ -98,3 +98,15 @@ enum AnimalTwo {
 enum Tagged {
     Test, // this should be { type: "Test" } in the TypeScript (not just the string "Test")
 }
+
+/// Unit variants of externally tagged enums are serialized as just their name,
+/// even if the enum has variants with data
+#[tsync]
+#[serde(rename_all = "kebab-case")]
+enum ExternalRenamed {
+    Missing,
+    #[serde(rename = "found")]
+    FoundIt(String),
+    Coordinates(i32, i32),
+    Detailed { x: i32, y: i32 },
+}
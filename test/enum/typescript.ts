@@ -14,12 +14,12 @@ export type InternalTopping =
  * Not vegetarian
  */
 type InternalTopping__Pepperoni = {
-  type: "PEPPERONI";
+  "type": "PEPPERONI";
 };
 /** For cheese lovers */
 type InternalTopping__ExtraCheese = {
-  type: "EXTRA CHEESE";
-  KIND: string;
+  "type": "EXTRACHEESE";
+  kind: string;
 };
 /**
  * Custom toppings
@@ -27,7 +27,8 @@ type InternalTopping__ExtraCheese = {
  * Note: because this is a newtype variant, it should be included in the typescript
  */
 type InternalTopping__Custom = {
-  type: "CUSTOM"} & CustomTopping
+  "type": "CUSTOM";
+} & CustomTopping;
 
 /**
  * Adjacently tagged enums have a key-value pair
@@ -45,12 +46,14 @@ export type AdjacentTopping =
  * Not vegetarian
  */
 type AdjacentTopping__Pepperoni = {
-  type: "Pepperoni";
+  "type": "Pepperoni";
 };
 /** For cheese lovers */
 type AdjacentTopping__ExtraCheese = {
-  type: "ExtraCheese";
-  kind: string;
+  "type": "ExtraCheese";
+  "value": {
+    kind: string;
+  };
 };
 /**
  * Custom toppings
@@ -66,7 +69,7 @@ type AdjacentTopping__Custom = {
  */
 type AdjacentTopping__CustomTwo = {
   "type": "CustomTwo";
-  "value": [ CustomTopping, CustomTopping ];
+  "value": [CustomTopping, CustomTopping];
 };
 
 /**
@@ -78,11 +81,7 @@ export type ExternalTopping =
    * Tasty!
    * Not vegetarian
    */
-  | {
-      "Pepperoni": {
-        [key: PropertyKey]: never;
-      }
-    }
+  | "Pepperoni"
   /** For cheese lovers */
   | {
       "ExtraCheese": {
@@ -99,7 +98,7 @@ export type ExternalTopping =
    * two custom toppings
    * Note: this test case is specifically for specifying a tuple of types
    */
-  | { "CustomTwo": [ CustomTopping, CustomTopping ] };
+  | { "CustomTwo": [CustomTopping, CustomTopping] };
 
 export interface CustomTopping {
   name: string;
@@ -126,5 +125,20 @@ export type Tagged =
   | Tagged__Test;
 
 type Tagged__Test = {
-  type: "Test";
+  "type": "Test";
 };
+
+/**
+ * Unit variants of externally tagged enums are serialized as just their name,
+ * even if the enum has variants with data
+ */
+export type ExternalRenamed =
+  | "missing"
+  | { "found": string }
+  | { "coordinates": [number, number] }
+  | {
+      "detailed": {
+        x: number;
+        y: number;
+      }
+    };
@@ -0,0 +1,21 @@
+/// test/rust.rs
+use tsync::tsync;
+
+/// Adjacently tagged enums keep the tag and the content
+/// of each variant in separate fields
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "t", content = "c", rename_all = "snake_case")]
+#[tsync]
+enum Block<T> {
+    /// Unit variants only have a tag
+    PageBreak,
+    /// Newtype variants have their value as the content
+    Paragraph(Vec<T>),
+    /// Tuple variants have an array as the content
+    Text(String, u32),
+    /// Struct variants have an object as the content
+    Heading { level: u8, text: String },
+    #[serde(rename = "hr")]
+    HorizontalRule,
+    Empty(),
+}
@@ -0,0 +1,43 @@
+/* This file is generated and managed by tsync */
+
+/**
+ * Adjacently tagged enums keep the tag and the content
+ * of each variant in separate fields
+ */
+type Block<T> =
+  | Block__PageBreak
+  | Block__Paragraph<T>
+  | Block__Text
+  | Block__Heading
+  | Block__HorizontalRule
+  | Block__Empty;
+
+/** Unit variants only have a tag */
+type Block__PageBreak = {
+  "t": "page_break";
+};
+/** Newtype variants have their value as the content */
+type Block__Paragraph<T> = {
+  "t": "paragraph";
+  "c": Array<T>;
+};
+/** Tuple variants have an array as the content */
+type Block__Text = {
+  "t": "text";
+  "c": [string, number];
+};
+/** Struct variants have an object as the content */
+type Block__Heading = {
+  "t": "heading";
+  "c": {
+    level: number;
+    text: string;
+  };
+};
+type Block__HorizontalRule = {
+  "t": "hr";
+};
+type Block__Empty = {
+  "t": "empty";
+  "c": [];
+};
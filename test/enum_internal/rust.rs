@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Meters(f64);
+
+// aliases declared before the items they refer to
+type LateColor = Color;
+type LateNothing = Nothing;
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+enum Color {
+    Red,
+    Blue,
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Nothing;
+
+/// Unit variants only contain the tag, newtype variants merge the tag into the
+/// fields of their struct
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum Shape {
+    Empty,
+    Origin(()),
+    /// unit structs are serialized as just the tag, like `()`
+    Blank(Nothing),
+    LateBlank(LateNothing),
+    Point(Point),
+    Labelled(HashMap<String, Point>),
+    #[serde(rename = "circle")]
+    Circle { center: Point, radius: u32 },
+}
+
+/// Variants which serde can't serialize with internal tagging are left out
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum Unsupported {
+    Supported { value: u32 },
+    Number(u32),
+    Text(String),
+    Distance(Meters),
+    Paint(Color),
+    LatePaint(LateColor),
+    List(Vec<Point>),
+    Pair(u32, u32),
+    Clashing {
+        #[serde(rename = "type")]
+        kind: String,
+    },
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum NothingSupported {
+    Number(u32),
+}
+
+/// Tags that aren't identifiers are quoted
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "@type")]
+enum Annotated {
+    Note { text: String },
+    Empty,
+}
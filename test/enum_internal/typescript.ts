@@ -0,0 +1,76 @@
+/* This file is generated and managed by tsync */
+
+export interface Point {
+  x: number;
+  y: number;
+}
+
+export type Meters = number
+
+export type Color =
+  | "Red" | "Blue";
+
+export type Nothing = null
+
+/**
+ * Unit variants only contain the tag, newtype variants merge the tag into the
+ * fields of their struct
+ */
+export type Shape =
+  | Shape__Empty
+  | Shape__Origin
+  | Shape__Blank
+  | Shape__LateBlank
+  | Shape__Point
+  | Shape__Labelled
+  | Shape__Circle;
+
+type Shape__Empty = {
+  "kind": "Empty";
+};
+type Shape__Origin = {
+  "kind": "Origin";
+};
+/** unit structs are serialized as just the tag, like `()` */
+type Shape__Blank = {
+  "kind": "Blank";
+};
+type Shape__LateBlank = {
+  "kind": "LateBlank";
+};
+type Shape__Point = {
+  "kind": "Point";
+} & Point;
+type Shape__Labelled = {
+  "kind": "Labelled";
+} & Record<string, Point>;
+type Shape__Circle = {
+  "kind": "circle";
+  center: Point;
+  radius: number;
+};
+
+/** Variants which serde can't serialize with internal tagging are left out */
+export type Unsupported =
+  | Unsupported__Supported;
+
+type Unsupported__Supported = {
+  "type": "Supported";
+  value: number;
+};
+
+export type NothingSupported = never;
+
+
+/** Tags that aren't identifiers are quoted */
+export type Annotated =
+  | Annotated__Note
+  | Annotated__Empty;
+
+type Annotated__Note = {
+  "@type": "Note";
+  text: string;
+};
+type Annotated__Empty = {
+  "@type": "Empty";
+};
@@ -5,9 +5,11 @@ type Message<Params, Value> =
   | Message__Response<Value>;
 
 type Message__Request<Params> = {
-  type: "Request"} & Request<Params>
+  "type": "Request";
+} & Request<Params>;
 type Message__Response<Value> = {
-  type: "Response"} & Response<Value>
+  "type": "Response";
+} & Response<Value>;
 
 interface Request<Params> {
   id: string;
@@ -25,12 +27,12 @@ type CaptureConfigurationStruct =
   | CaptureConfigurationStruct__Redirect;
 
 type CaptureConfigurationStruct__Video = {
-  type: "video";
+  "type": "video";
   height: number;
   width: number;
 };
 type CaptureConfigurationStruct__Redirect = {
-  type: "redirect";
+  "type": "redirect";
 };
 
 /** cases below were provided by joaoantoniocardoso on github in the discussion for issue #58 */
@@ -39,9 +41,11 @@ type CaptureConfigurationNewtype =
   | CaptureConfigurationNewtype__Redirect;
 
 type CaptureConfigurationNewtype__Video = {
-  type: "video"} & VideoCaptureConfiguration
+  "type": "video";
+} & VideoCaptureConfiguration;
 type CaptureConfigurationNewtype__Redirect = {
-  type: "redirect"} & RedirectCaptureConfiguration
+  "type": "redirect";
+} & RedirectCaptureConfiguration;
 
 interface VideoCaptureConfiguration {
   height: number;
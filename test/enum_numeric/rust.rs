@@ -8,3 +8,14 @@ enum Foo {
     Baz = 123, // 123
     Quux,      // 124
 }
+
+/// Skipped variants still take up a value
+#[derive(Serialize_repr)]
+#[tsync]
+enum Level {
+    Low, // 0
+    #[tsync(skip)]
+    Internal, // 1
+    #[tsync(rename = "High")]
+    Top, // 2
+}
@@ -6,3 +6,9 @@ declare enum Foo {
   Baz = 123,
   Quux = 124,
 }
+
+/** Skipped variants still take up a value */
+declare enum Level {
+  Low = 0,
+  High = 2,
+}
@@ -6,3 +6,9 @@ export enum Foo {
   Baz = 123,
   Quux = 124,
 }
+
+/** Skipped variants still take up a value */
+export enum Level {
+  Low = 0,
+  High = 2,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// `rename_all` renames variants, `rename_all_fields` renames the fields of struct variants
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", rename_all_fields = "camelCase")]
+enum Request {
+    GetUser { user_id: u32 },
+    ListUsers { page_size: u32, page_token: Option<String> },
+    // the casing of a variant takes precedence over the enum's `rename_all_fields`
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    DeleteUser { user_id: u32 },
+}
+
+/// The types describe serialized values, so serialize casings and names are used
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all(serialize = "kebab-case", deserialize = "snake_case"))]
+enum Response {
+    UserFound { user_name: String },
+    #[serde(rename(serialize = "missing", deserialize = "not_found"))]
+    NotFound,
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all(serialize = "camelCase"))]
+struct User {
+    user_id: u32,
+    #[serde(rename(serialize = "name"))]
+    user_name: String,
+}
@@ -0,0 +1,35 @@
+/* This file is generated and managed by tsync */
+
+/** `rename_all` renames variants, `rename_all_fields` renames the fields of struct variants */
+type Request =
+  | Request__GetUser
+  | Request__ListUsers
+  | Request__DeleteUser;
+
+type Request__GetUser = {
+  "type": "get_user";
+  userId: number;
+};
+type Request__ListUsers = {
+  "type": "list_users";
+  pageSize: number;
+  pageToken?: string;
+};
+type Request__DeleteUser = {
+  "type": "delete_user";
+  USER_ID: number;
+};
+
+/** The types describe serialized values, so serialize casings and names are used */
+type Response =
+  | {
+      "user-found": {
+        user_name: string;
+      }
+    }
+  | "missing";
+
+interface User {
+  userId: number;
+  name: string;
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Setting<T> {
+    /// serialized as `null`
+    Unset,
+    /// serialized as `[]`
+    Empty(),
+    /// serialized as its field
+    Value(T),
+    /// serialized as an array of both fields
+    Pair(u32, String),
+    /// the `None` element is kept, as an optional type like elsewhere
+    MaybePair(u32, Option<String>),
+    Named { value: T, default: Option<T> },
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Nothing {
+    A,
+    B,
+}
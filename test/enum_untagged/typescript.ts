@@ -0,0 +1,21 @@
+/* This file is generated and managed by tsync */
+
+export type Setting<T> =
+  /** serialized as `null` */
+  | null
+  /** serialized as `[]` */
+  | []
+  /** serialized as its field */
+  | T
+  /** serialized as an array of both fields */
+  | [number, string]
+  /** the `None` element is kept, as an optional type like elsewhere */
+  | [number, string | undefined]
+  | {
+      value: T;
+      default?: T;
+    };
+
+export type Nothing =
+  | null
+  | null;
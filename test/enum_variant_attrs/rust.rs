@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// Untagged variants are listed after the tagged ones, like serde tries them
+#[tsync]
+#[derive(Serialize, Deserialize)]
+enum Value {
+    Number(f64),
+    Text { text: String },
+    #[serde(untagged)]
+    Raw(String),
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum Event {
+    Started { at: u64 },
+    Stopped,
+    // any unknown type is deserialized as this variant
+    #[serde(other)]
+    Unknown,
+    #[serde(untagged)]
+    Custom { name: String },
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "t", content = "c")]
+enum Message {
+    Text(String),
+    #[serde(other)]
+    Unsupported,
+    #[serde(untagged, rename = "raw")]
+    Raw(Vec<u8>),
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+enum Level {
+    Low,
+    High,
+    #[serde(untagged)]
+    Unset,
+}
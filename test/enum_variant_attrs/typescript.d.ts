@@ -0,0 +1,46 @@
+/* This file is generated and managed by tsync */
+
+/** Untagged variants are listed after the tagged ones, like serde tries them */
+type Value =
+  | { "Number": number }
+  | {
+      "Text": {
+        text: string;
+      }
+    }
+  | string;
+
+type Event =
+  | Event__Started
+  | Event__Stopped
+  | Event__Unknown
+  | {
+      name: string;
+    };
+
+type Event__Started = {
+  "type": "Started";
+  at: number;
+};
+type Event__Stopped = {
+  "type": "Stopped";
+};
+type Event__Unknown = {
+  "type": string;
+};
+
+type Message =
+  | Message__Text
+  | Message__Unsupported
+  | Array<number>;
+
+type Message__Text = {
+  "t": "Text";
+  "c": string;
+};
+type Message__Unsupported = {
+  "t": string;
+};
+
+type Level =
+  | "Low" | "High" | null;
@@ -0,0 +1,46 @@
+/* This file is generated and managed by tsync */
+
+/** Untagged variants are listed after the tagged ones, like serde tries them */
+export type Value =
+  | { "Number": number }
+  | {
+      "Text": {
+        text: string;
+      }
+    }
+  | string;
+
+export type Event =
+  | Event__Started
+  | Event__Stopped
+  | Event__Unknown
+  | {
+      name: string;
+    };
+
+type Event__Started = {
+  "type": "Started";
+  at: number;
+};
+type Event__Stopped = {
+  "type": "Stopped";
+};
+type Event__Unknown = {
+  "type": string;
+};
+
+export type Message =
+  | Message__Text
+  | Message__Unsupported
+  | Array<number>;
+
+type Message__Text = {
+  "t": "Text";
+  "c": string;
+};
+type Message__Unsupported = {
+  "t": string;
+};
+
+export type Level =
+  | "Low" | "High" | null;
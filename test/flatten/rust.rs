@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Pagination {
+    page: u32,
+    per_page: u32,
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum Filter {
+    ByName { name: String },
+    ByAge { min: u32, max: u32 },
+}
+
+/// Flattened maps add their entries to the object
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Config {
+    name: String,
+    #[serde(flatten)]
+    extra: HashMap<String, Value>,
+}
+
+/// The index signature of a flattened map allows the other fields too
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Counters {
+    total: u64,
+    label: Option<String>,
+    #[serde(flatten)]
+    counts: HashMap<String, u32>,
+}
+
+/// Flattened options may leave all of their fields out
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Query {
+    search: String,
+    #[serde(flatten)]
+    pagination: Option<Pagination>,
+    #[serde(flatten)]
+    filter: Filter,
+    #[serde(flatten)]
+    labels: BTreeMap<u32, Option<String>>,
+}
+
+/// Flattened values which aren't objects are left out
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Invalid {
+    id: u32,
+    #[serde(flatten)]
+    tags: Vec<String>,
+}
+
+/// Flattened unions are kept together
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Outcome {
+    id: u32,
+    #[serde(flatten)]
+    result: Result<Pagination, Filter>,
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Pair(#[serde(flatten)] Pagination, u32);
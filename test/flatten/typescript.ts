@@ -0,0 +1,56 @@
+/* This file is generated and managed by tsync */
+
+export type JsonValue =
+  | string
+  | number
+  | boolean
+  | null
+  | Array<JsonValue>
+  | { [key: string]: JsonValue };
+
+export interface Pagination {
+  page: number;
+  per_page: number;
+}
+
+export type Filter =
+  | Filter__ByName
+  | Filter__ByAge;
+
+type Filter__ByName = {
+  "kind": "ByName";
+  name: string;
+};
+type Filter__ByAge = {
+  "kind": "ByAge";
+  min: number;
+  max: number;
+};
+
+/** Flattened maps add their entries to the object */
+export type Config = { [key: string]: JsonValue | string } & {
+  name: string;
+}
+
+/** The index signature of a flattened map allows the other fields too */
+export type Counters = { [key: string]: number | string | undefined } & {
+  total: number;
+  label?: string;
+}
+
+/** Flattened options may leave all of their fields out */
+export type Query = Partial<Pagination> & Filter & { [key: string]: unknown } & {
+  search: string;
+}
+
+/** Flattened values which aren't objects are left out */
+export interface Invalid {
+  id: number;
+}
+
+/** Flattened unions are kept together */
+export type Outcome = ({ Ok: Pagination } | { Err: Filter }) & {
+  id: number;
+}
+
+export type Pair = [Pagination, number]
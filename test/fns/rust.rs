@@ -0,0 +1,45 @@
+use axum::extract::{Json, Path};
+use actix_web::web::Data;
+
+#[tsync]
+struct User {
+    id: u64,
+    name: String,
+}
+
+/// Fetch a user by id
+#[tsync]
+async fn get_user(id: u32) -> Result<Json<User>, ApiError> {
+    todo!()
+}
+
+// arguments written as patterns are named after their binding
+#[tsync]
+async fn update_user(Path(id): Path<u32>, Json(user): Json<User>) -> Result<(), ApiError> {
+    todo!()
+}
+
+#[tsync]
+fn list_users(page: Option<u32>, tags: Vec<String>) -> Vec<User> {
+    todo!()
+}
+
+#[tsync]
+fn health() {}
+
+// `Data` and `Path` are unwrapped with --unwrap-type
+#[tsync]
+fn get_config(#[tsync(skip)] pool: Data<Pool>, #[tsync(rename = "configId", as = "bigint")] id: u64) -> Data<Config> {
+    todo!()
+}
+
+#[tsync(rename = "fetchAll")]
+fn fetch_all_items<T>(filter: T) -> Vec<T> {
+    todo!()
+}
+
+#[tsync(type = "(id: number) => void")]
+fn custom(id: u32) {}
+
+// not exported
+fn internal() {}
@@ -0,0 +1,33 @@
+/* This file is generated and managed by tsync */
+
+interface User {
+  id: number;
+  name: string;
+}
+
+/** Fetch a user by id */
+type getUser = (args: {
+  id: number;
+}) => Promise<User>
+
+type updateUser = (args: {
+  id: number;
+  user: User;
+}) => Promise<void>
+
+type listUsers = (args: {
+  page?: number;
+  tags: Array<string>;
+}) => Promise<Array<User>>
+
+type health = () => Promise<void>
+
+type getConfig = (args: {
+  configId: bigint;
+}) => Promise<Config>
+
+type fetchAll = <T>(args: {
+  filter: T;
+}) => Promise<Array<T>>
+
+type custom = (id: number) => void
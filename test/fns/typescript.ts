@@ -0,0 +1,33 @@
+/* This file is generated and managed by tsync */
+
+export interface User {
+  id: number;
+  name: string;
+}
+
+/** Fetch a user by id */
+export type getUser = (args: {
+  id: number;
+}) => Promise<User>
+
+export type updateUser = (args: {
+  id: number;
+  user: User;
+}) => Promise<void>
+
+export type listUsers = (args: {
+  page?: number;
+  tags: Array<string>;
+}) => Promise<Array<User>>
+
+export type health = () => Promise<void>
+
+export type getConfig = (args: {
+  configId: bigint;
+}) => Promise<Config>
+
+export type fetchAll = <T>(args: {
+  filter: T;
+}) => Promise<Array<T>>
+
+export type custom = (id: number) => void
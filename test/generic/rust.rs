@@ -48,3 +48,30 @@ enum InternalEnum<T, U> {
     Bar { value: T, alias: String },
     Waz(U),
 }
+
+// Test generic type aliases and defaults
+
+#[tsync]
+type Page<T> = Vec<T>;
+
+#[tsync]
+type Lookup<K, V = String> = HashMap<K, V>;
+
+#[tsync]
+struct Paged<T = Folder> {
+    items: Page<T>,
+    cursor: Option<String>,
+}
+
+#[tsync]
+#[serde(untagged)]
+enum UntaggedEnum<T = u32> {
+    Bar(T),
+    Waz { value: T },
+}
+
+#[tsync]
+#[serde(tag = "kind")]
+enum InternalDefaultEnum<T = String> {
+    Bar { value: T },
+}
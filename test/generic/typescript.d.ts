@@ -11,7 +11,7 @@ interface Paginated<T> {
   total_pages: number;
 }
 
-type Flatten<T> = Array<T> & {
+interface Flatten<T> {
   name: string;
 }
 
@@ -38,9 +38,33 @@ type InternalEnum<T, U> =
   | InternalEnum__Waz<U>;
 
 type InternalEnum__Bar<T> = {
-  type: "Bar";
+  "type": "Bar";
   value: T;
   alias: string;
 };
 type InternalEnum__Waz<U> = {
-  type: "Waz"} & U
+  "type": "Waz";
+} & U;
+
+type Page<T> = Array<T>
+
+type Lookup<K, V = string> = Record<K, V>
+
+interface Paged<T = Folder> {
+  items: Page<T>;
+  cursor?: string;
+}
+
+type UntaggedEnum<T = number> =
+  | T
+  | {
+      value: T;
+    };
+
+type InternalDefaultEnum<T = string> =
+  | InternalDefaultEnum__Bar<T>;
+
+type InternalDefaultEnum__Bar<T> = {
+  "kind": "Bar";
+  value: T;
+};
@@ -11,7 +11,7 @@ export interface Paginated<T> {
   total_pages: number;
 }
 
-export type Flatten<T> = Array<T> & {
+export interface Flatten<T> {
   name: string;
 }
 
@@ -38,9 +38,33 @@ export type InternalEnum<T, U> =
   | InternalEnum__Waz<U>;
 
 type InternalEnum__Bar<T> = {
-  type: "Bar";
+  "type": "Bar";
   value: T;
   alias: string;
 };
 type InternalEnum__Waz<U> = {
-  type: "Waz"} & U
+  "type": "Waz";
+} & U;
+
+export type Page<T> = Array<T>
+
+export type Lookup<K, V = string> = Record<K, V>
+
+export interface Paged<T = Folder> {
+  items: Page<T>;
+  cursor?: string;
+}
+
+export type UntaggedEnum<T = number> =
+  | T
+  | {
+      value: T;
+    };
+
+export type InternalDefaultEnum<T = string> =
+  | InternalDefaultEnum__Bar<T>;
+
+type InternalDefaultEnum__Bar<T> = {
+  "kind": "Bar";
+  value: T;
+};
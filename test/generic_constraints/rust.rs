@@ -0,0 +1,47 @@
+/// test/rust.rs
+use std::borrow::Cow;
+use tsync::tsync;
+
+#[tsync]
+type UserId = String;
+
+#[tsync]
+struct User {
+    name: String,
+}
+
+/// Conversion bounds to known types become constraints,
+/// other bounds are left out
+#[tsync]
+struct Lookup<K: Into<UserId> + Clone, V = User>
+where
+    V: AsRef<User>,
+{
+    key: K,
+    value: V,
+}
+
+#[tsync]
+struct Labelled<T: std::fmt::Display, L: Into<String>> {
+    value: T,
+    label: L,
+}
+
+/// Const generics become type parameters constrained to the type of their value
+#[tsync]
+struct Buffer<const N: usize = 16> {
+    data: [u8; N],
+}
+
+#[tsync]
+struct Packet {
+    header: Buffer<4>,
+    body: Buffer<{ 512 }>,
+    name: Cow<'static, str>,
+}
+
+#[tsync]
+enum Shape<T: Into<UserId>> {
+    Named(T),
+    Anonymous { id: u32 },
+}
@@ -0,0 +1,40 @@
+/* This file is generated and managed by tsync */
+
+type UserId = string
+
+interface User {
+  name: string;
+}
+
+/**
+ * Conversion bounds to known types become constraints,
+ * other bounds are left out
+ */
+interface Lookup<K extends UserId, V extends User = User> {
+  key: K;
+  value: V;
+}
+
+interface Labelled<T, L extends string> {
+  value: T;
+  label: L;
+}
+
+/** Const generics become type parameters constrained to the type of their value */
+interface Buffer<N extends number = 16> {
+  data: Array<number>;
+}
+
+interface Packet {
+  header: Buffer<4>;
+  body: Buffer<512>;
+  name: string;
+}
+
+type Shape<T extends UserId> =
+  | { "Named": T }
+  | {
+      "Anonymous": {
+        id: number;
+      }
+    };
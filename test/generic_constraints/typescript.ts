@@ -0,0 +1,40 @@
+/* This file is generated and managed by tsync */
+
+export type UserId = string
+
+export interface User {
+  name: string;
+}
+
+/**
+ * Conversion bounds to known types become constraints,
+ * other bounds are left out
+ */
+export interface Lookup<K extends UserId, V extends User = User> {
+  key: K;
+  value: V;
+}
+
+export interface Labelled<T, L extends string> {
+  value: T;
+  label: L;
+}
+
+/** Const generics become type parameters constrained to the type of their value */
+export interface Buffer<N extends number = 16> {
+  data: Array<number>;
+}
+
+export interface Packet {
+  header: Buffer<4>;
+  body: Buffer<512>;
+  name: string;
+}
+
+export type Shape<T extends UserId> =
+  | { "Named": T }
+  | {
+      "Anonymous": {
+        id: number;
+      }
+    };
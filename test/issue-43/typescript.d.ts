@@ -10,6 +10,6 @@ interface HasTuple {
   pong?: [number, string, [string, [number, number] | undefined] | undefined];
 }
 
-type IsTuple = [ number, string ]
+type IsTuple = [number, string]
 
-type IsTupleComplex = [ number, string, [string, [number, number]] ]
+type IsTupleComplex = [number, string, [string, [number, number]]]
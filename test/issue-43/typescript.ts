@@ -10,6 +10,6 @@ export interface HasTuple {
   pong?: [number, string, [string, [number, number] | undefined] | undefined];
 }
 
-export type IsTuple = [ number, string ]
+export type IsTuple = [number, string]
 
-export type IsTupleComplex = [ number, string, [string, [number, number]] ]
+export type IsTupleComplex = [number, string, [string, [number, number]]]
@@ -1,7 +1,7 @@
 /* This file is generated and managed by tsync */
 
 type Message =
-  | [ number, number ]
+  | [number, number]
   | number;
 
 type Message2<V, G> =
@@ -1,7 +1,7 @@
 /* This file is generated and managed by tsync */
 
 export type Message =
-  | [ number, number ]
+  | [number, number]
   | number;
 
 export type Message2<V, G> =
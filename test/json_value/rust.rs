@@ -0,0 +1,18 @@
+/// test/rust.rs
+use serde_json::value::RawValue;
+use serde_json::{Map, Value};
+use tsync::tsync;
+
+/// `serde_json::Value` maps to the generated recursive `JsonValue` type
+#[tsync]
+struct Event {
+    name: String,
+    payload: serde_json::Value,
+    metadata: Map<String, Value>,
+    extra: Option<Value>,
+    history: Vec<serde_json::Value>,
+    raw: Box<RawValue>,
+}
+
+#[tsync]
+type Settings = serde_json::Map<String, serde_json::Value>;
@@ -0,0 +1,21 @@
+/* This file is generated and managed by tsync */
+
+type JsonValue =
+  | string
+  | number
+  | boolean
+  | null
+  | Array<JsonValue>
+  | { [key: string]: JsonValue };
+
+/** `serde_json::Value` maps to the generated recursive `JsonValue` type */
+interface Event {
+  name: string;
+  payload: JsonValue;
+  metadata: Record<string, JsonValue>;
+  extra?: JsonValue;
+  history: Array<JsonValue>;
+  raw: JsonValue;
+}
+
+type Settings = Record<string, JsonValue>
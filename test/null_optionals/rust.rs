@@ -0,0 +1,26 @@
+/// test/rust.rs
+use tsync::tsync;
+
+#[tsync]
+struct User {
+    name: String,
+}
+
+/// With `--null-optionals`, `None` is written as `null` like serde serializes it
+#[tsync]
+type MaybeUser = Option<User>;
+
+#[tsync]
+type Maybe<T> = std::option::Option<T>;
+
+/// Fields typed with an optional alias aren't given another `| null`
+#[tsync]
+struct Team {
+    lead: MaybeUser,
+    deputy: Maybe<User>,
+    members: Vec<MaybeUser>,
+    nickname: Option<String>,
+    aliases: Vec<Option<String>>,
+    /// already null when it's `Some(())`
+    acknowledged: Option<()>,
+}
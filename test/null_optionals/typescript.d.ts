@@ -0,0 +1,21 @@
+/* This file is generated and managed by tsync */
+
+interface User {
+  name: string;
+}
+
+/** With `--null-optionals`, `None` is written as `null` like serde serializes it */
+type MaybeUser = User | null
+
+type Maybe<T> = T | null
+
+/** Fields typed with an optional alias aren't given another `| null` */
+interface Team {
+  lead: MaybeUser;
+  deputy: Maybe<User>;
+  members: Array<MaybeUser>;
+  nickname: string | null;
+  aliases: Array<string | null>;
+  /** already null when it's `Some(())` */
+  acknowledged: null;
+}
@@ -0,0 +1,21 @@
+/* This file is generated and managed by tsync */
+
+export interface User {
+  name: string;
+}
+
+/** With `--null-optionals`, `None` is written as `null` like serde serializes it */
+export type MaybeUser = User | null
+
+export type Maybe<T> = T | null
+
+/** Fields typed with an optional alias aren't given another `| null` */
+export interface Team {
+  lead: MaybeUser;
+  deputy: Maybe<User>;
+  members: Array<MaybeUser>;
+  nickname: string | null;
+  aliases: Array<string | null>;
+  /** already null when it's `Some(())` */
+  acknowledged: null;
+}
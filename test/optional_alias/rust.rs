@@ -0,0 +1,23 @@
+/// test/rust.rs
+use tsync::tsync;
+
+#[tsync]
+struct User {
+    name: String,
+}
+
+/// Aliases of `Option` keep the `None` case
+#[tsync]
+type MaybeUser = Option<User>;
+
+#[tsync]
+type Maybe<T> = std::option::Option<T>;
+
+/// Fields typed with an optional alias are optional too
+#[tsync]
+struct Team {
+    lead: MaybeUser,
+    deputy: Maybe<User>,
+    members: Vec<MaybeUser>,
+    nickname: Option<String>,
+}
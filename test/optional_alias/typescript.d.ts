@@ -0,0 +1,18 @@
+/* This file is generated and managed by tsync */
+
+interface User {
+  name: string;
+}
+
+/** Aliases of `Option` keep the `None` case */
+type MaybeUser = User | undefined
+
+type Maybe<T> = T | undefined
+
+/** Fields typed with an optional alias are optional too */
+interface Team {
+  lead?: MaybeUser;
+  deputy?: Maybe<User>;
+  members: Array<MaybeUser>;
+  nickname?: string;
+}
@@ -0,0 +1,18 @@
+/* This file is generated and managed by tsync */
+
+export interface User {
+  name: string;
+}
+
+/** Aliases of `Option` keep the `None` case */
+export type MaybeUser = User | undefined
+
+export type Maybe<T> = T | undefined
+
+/** Fields typed with an optional alias are optional too */
+export interface Team {
+  lead?: MaybeUser;
+  deputy?: Maybe<User>;
+  members: Array<MaybeUser>;
+  nickname?: string;
+}
@@ -0,0 +1,18 @@
+use sqlx::types::Uuid;
+use tsync::tsync;
+
+mod models;
+mod time;
+
+/// Paths are resolved with the `use` items of each file, so our own `DateTime`
+/// isn't mistaken for chrono's `DateTime`
+#[tsync]
+struct Session {
+    /// types re-exported by other crates are still mapped
+    id: Uuid,
+    token: sqlx::types::Uuid,
+    user: models::v2::User,
+    legacy_user: crate::models::v1::User,
+    started_at: chrono::DateTime<chrono::Utc>,
+    expires_at: time::DateTime,
+}
@@ -0,0 +1,6 @@
+use tsync::tsync;
+
+#[tsync]
+struct User {
+    name: String,
+}
@@ -0,0 +1,21 @@
+use chrono::DateTime as ChronoDateTime;
+use tsync::tsync;
+
+use super::v1;
+use crate::time::DateTime;
+
+#[tsync]
+struct User {
+    first_name: String,
+    last_name: String,
+    created_at: DateTime,
+    updated_at: ChronoDateTime<Utc>,
+    previous: Option<v1::User>,
+    role: Role,
+}
+
+#[tsync]
+enum Role {
+    Admin,
+    Member,
+}
@@ -0,0 +1,7 @@
+use tsync::tsync;
+
+/// Our own `DateTime`, not chrono's
+#[tsync]
+struct DateTime {
+    seconds: u64,
+}
@@ -0,0 +1,36 @@
+/* This file is generated and managed by tsync */
+
+/**
+ * Paths are resolved with the `use` items of each file, so our own `DateTime`
+ * isn't mistaken for chrono's `DateTime`
+ */
+interface Session {
+  /** types re-exported by other crates are still mapped */
+  id: string;
+  token: string;
+  user: V2User;
+  legacy_user: V1User;
+  started_at: Date;
+  expires_at: DateTime;
+}
+
+interface V1User {
+  name: string;
+}
+
+interface V2User {
+  first_name: string;
+  last_name: string;
+  created_at: DateTime;
+  updated_at: Date;
+  previous?: V1User;
+  role: Role;
+}
+
+type Role =
+  | "Admin" | "Member";
+
+/** Our own `DateTime`, not chrono's */
+interface DateTime {
+  seconds: number;
+}
@@ -17,7 +17,7 @@ export type RawIdentifierEnum =
   | "type" | "async" | "match" | "NormalVariant";
 
 export type RawIdentifierEnumUppercase =
-  | "TYPE" | "CONST" | "NORMAL VARIANT";
+  | "TYPE" | "CONST" | "NORMALVARIANT";
 
 export type RawIdentifierNumericEnum =
   | "type" | "async" | "NormalVariant";
@@ -28,15 +28,15 @@ export type RawIdentifierTaggedEnum =
   | RawIdentifierTaggedEnum__NormalVariant;
 
 type RawIdentifierTaggedEnum__type = {
-  kind: "type";
+  "kind": "type";
   value: string;
 };
 type RawIdentifierTaggedEnum__async = {
-  kind: "async";
+  "kind": "async";
   count: number;
 };
 type RawIdentifierTaggedEnum__NormalVariant = {
-  kind: "NormalVariant";
+  "kind": "NormalVariant";
   data: boolean;
 };
 
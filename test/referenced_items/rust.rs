@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+// only the root type is marked, the types it references are exported too
+#[tsync]
+struct Order<T> {
+    customer: Customer,
+    items: Vec<LineItem>,
+    metadata: HashMap<String, Metadata>,
+    payload: T,
+    #[tsync(skip)]
+    internal: InternalState,
+}
+
+struct Customer {
+    name: String,
+    address: Option<Box<Address>>,
+}
+
+struct Address {
+    street: String,
+    country: Country,
+}
+
+enum Country {
+    France,
+    Japan,
+}
+
+// referenced from a variant and a generic argument
+enum LineItem {
+    Product { product: Product },
+    Discount(Wrapper<Discount>),
+}
+
+struct Product {
+    price: Price,
+}
+
+type Price = u32;
+
+struct Discount {
+    percent: u8,
+}
+
+struct Wrapper<T> {
+    inner: T,
+    parent: Option<Box<Wrapper<T>>>,
+}
+
+struct Metadata {
+    value: String,
+}
+
+struct InternalState {
+    counter: u32,
+}
+
+// not referenced by any exported type
+struct Unrelated {
+    value: String,
+}
+
+// the types in the signatures of exported functions are exported too, except the
+// wrapper types like `Result` which are unwrapped
+#[tsync]
+fn get_user(id: UserId) -> Result<Option<User>, ApiError> {
+    todo!()
+}
+
+type UserId = u64;
+
+struct User {
+    id: UserId,
+    name: String,
+}
+
+struct ApiError {
+    message: String,
+}
+
+// the std `Result` isn't mistaken for an alias with the same name
+#[tsync]
+struct Report {
+    outcome: Result<u32, String>,
+}
+
+mod db {
+    pub type Result<T> = std::result::Result<T, Error>;
+
+    pub struct Error {
+        code: u32,
+    }
+}
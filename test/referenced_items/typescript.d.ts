@@ -0,0 +1,63 @@
+/* This file is generated and managed by tsync */
+
+interface Order<T> {
+  customer: Customer;
+  items: Array<LineItem>;
+  metadata: Record<string, Metadata>;
+  payload: T;
+}
+
+interface Customer {
+  name: string;
+  address?: Address;
+}
+
+interface Address {
+  street: string;
+  country: Country;
+}
+
+type Country =
+  | "France" | "Japan";
+
+type LineItem =
+  | {
+      "Product": {
+        product: Product;
+      }
+    }
+  | { "Discount": Wrapper<Discount> };
+
+interface Product {
+  price: Price;
+}
+
+type Price = number
+
+interface Discount {
+  percent: number;
+}
+
+interface Wrapper<T> {
+  inner: T;
+  parent?: Wrapper<T>;
+}
+
+interface Metadata {
+  value: string;
+}
+
+type getUser = (args: {
+  id: UserId;
+}) => Promise<User | undefined>
+
+type UserId = number
+
+interface User {
+  id: UserId;
+  name: string;
+}
+
+interface Report {
+  outcome: { Ok: number } | { Err: string };
+}
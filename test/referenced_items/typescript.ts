@@ -0,0 +1,63 @@
+/* This file is generated and managed by tsync */
+
+export interface Order<T> {
+  customer: Customer;
+  items: Array<LineItem>;
+  metadata: Record<string, Metadata>;
+  payload: T;
+}
+
+export interface Customer {
+  name: string;
+  address?: Address;
+}
+
+export interface Address {
+  street: string;
+  country: Country;
+}
+
+export type Country =
+  | "France" | "Japan";
+
+export type LineItem =
+  | {
+      "Product": {
+        product: Product;
+      }
+    }
+  | { "Discount": Wrapper<Discount> };
+
+export interface Product {
+  price: Price;
+}
+
+export type Price = number
+
+export interface Discount {
+  percent: number;
+}
+
+export interface Wrapper<T> {
+  inner: T;
+  parent?: Wrapper<T>;
+}
+
+export interface Metadata {
+  value: string;
+}
+
+export type getUser = (args: {
+  id: UserId;
+}) => Promise<User | undefined>
+
+export type UserId = number
+
+export interface User {
+  id: UserId;
+  name: string;
+}
+
+export interface Report {
+  outcome: { Ok: number } | { Err: string };
+}
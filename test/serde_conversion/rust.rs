@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+/// Serialized as an `Rgb` rather than as its own fields
+#[tsync]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "Rgb", from = "Rgb")]
+struct Color {
+    packed: u32,
+}
+
+/// Serialized as a version string
+#[tsync]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+struct Version {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+#[tsync]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "Vec<T>")]
+enum Items<T> {
+    None,
+    One(T),
+    Many(Vec<T>),
+}
+
+#[tsync]
+#[derive(Deserialize)]
+#[serde(from = "Option<u32>")]
+struct Limit {
+    value: u32,
+}
+
+/// Serialized as a string rather than as null
+#[tsync]
+#[derive(Clone, Serialize)]
+#[serde(into = "String")]
+struct Marker;
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Wire {
+    celsius: String,
+}
+
+/// Only deserialized from a `Wire`, so it's still serialized as its own fields
+#[tsync]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(from = "Wire")]
+struct Temperature {
+    celsius: f64,
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct Theme {
+    background: Color,
+    version: Version,
+    items: Items<Color>,
+    limit: Limit,
+    temperature: Temperature,
+    marker: Marker,
+}
@@ -0,0 +1,38 @@
+/* This file is generated and managed by tsync */
+
+interface Rgb {
+  r: number;
+  g: number;
+  b: number;
+}
+
+/** Serialized as an `Rgb` rather than as its own fields */
+type Color = Rgb
+
+/** Serialized as a version string */
+type Version = string
+
+type Items<T> = Array<T>
+
+type Limit = number | undefined
+
+/** Serialized as a string rather than as null */
+type Marker = string
+
+interface Wire {
+  celsius: string;
+}
+
+/** Only deserialized from a `Wire`, so it's still serialized as its own fields */
+interface Temperature {
+  celsius: number;
+}
+
+interface Theme {
+  background: Color;
+  version: Version;
+  items: Items<Color>;
+  limit?: Limit;
+  temperature: Temperature;
+  marker: Marker;
+}
@@ -0,0 +1,38 @@
+/* This file is generated and managed by tsync */
+
+export interface Rgb {
+  r: number;
+  g: number;
+  b: number;
+}
+
+/** Serialized as an `Rgb` rather than as its own fields */
+export type Color = Rgb
+
+/** Serialized as a version string */
+export type Version = string
+
+export type Items<T> = Array<T>
+
+export type Limit = number | undefined
+
+/** Serialized as a string rather than as null */
+export type Marker = string
+
+export interface Wire {
+  celsius: string;
+}
+
+/** Only deserialized from a `Wire`, so it's still serialized as its own fields */
+export interface Temperature {
+  celsius: number;
+}
+
+export interface Theme {
+  background: Color;
+  version: Version;
+  items: Items<Color>;
+  limit?: Limit;
+  temperature: Temperature;
+  marker: Marker;
+}
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+// not exported: its path doesn't match any glob
+#[derive(Serialize)]
+pub struct Cache {
+    size: u32,
+}
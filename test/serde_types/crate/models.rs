@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+// exported without #[tsync], since it derives Serialize and is pub
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    user_name: String,
+    role: Role,
+}
+
+#[derive(serde::Deserialize)]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+// not exported: it isn't pub
+#[derive(Serialize, Deserialize)]
+struct Session {
+    token: String,
+}
+
+// not exported: it doesn't derive serde traits
+pub struct Connection {
+    url: String,
+}
+
+// items marked with #[tsync] are exported in any case
+#[tsync]
+struct Marked {
+    value: u32,
+}
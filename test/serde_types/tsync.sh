@@ -0,0 +1,8 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+cargo run -- -i crate -o typescript.d.ts --serde-types --pub-only --serde-path 'crate::models::*' --serde-path 'crate::api::**'
+cargo run -- -i crate -o typescript.ts --serde-types --pub-only --serde-path 'crate::models::*' --serde-path 'crate::api::**'
@@ -0,0 +1,17 @@
+/* This file is generated and managed by tsync */
+
+interface Response {
+  ok: boolean;
+}
+
+interface User {
+  userName: string;
+  role: Role;
+}
+
+type Role =
+  | "Admin" | "Member";
+
+interface Marked {
+  value: number;
+}
@@ -0,0 +1,17 @@
+/* This file is generated and managed by tsync */
+
+export interface Response {
+  ok: boolean;
+}
+
+export interface User {
+  userName: string;
+  role: Role;
+}
+
+export type Role =
+  | "Admin" | "Member";
+
+export interface Marked {
+  value: number;
+}
@@ -64,10 +64,10 @@ export type BookType =
   | BookType__NonFiction;
 
 type BookType__Fiction = {
-  type: "Fiction";
+  "type": "fiction";
   genre: string;
 };
 type BookType__NonFiction = {
-  type: "NonFiction";
+  "type": "non-fiction";
   subject: string;
 };
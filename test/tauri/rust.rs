@@ -0,0 +1,40 @@
+use tauri::{AppHandle, State};
+
+#[tsync]
+struct User {
+    id: u32,
+    user_name: String,
+}
+
+/// Fetch a user, the state is provided by tauri
+#[tsync]
+#[tauri::command]
+async fn get_user(user_id: u32, state: State<'_, Db>) -> Result<User, String> {
+    todo!()
+}
+
+// optional trailing arguments can be left out
+#[tauri::command]
+fn search_users(query: String, page_size: Option<u32>, max_pages: Option<u32>) -> Vec<User> {
+    todo!()
+}
+
+#[tauri::command(rename_all = "snake_case")]
+fn rename_user(app: AppHandle, user_id: u32, new_name: Option<String>, notify: bool) {
+    todo!()
+}
+
+// renamed parameters are still sent with the key tauri expects
+#[tauri::command]
+fn delete_user(#[tsync(rename = "id")] user_id: u32) {
+    todo!()
+}
+
+#[tsync(rename = "ping")]
+#[command]
+fn health_check() -> bool {
+    true
+}
+
+// not a command, and not marked with #[tsync]
+fn helper() {}
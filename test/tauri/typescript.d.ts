@@ -0,0 +1,17 @@
+/* This file is generated and managed by tsync */
+
+interface User {
+  id: number;
+  user_name: string;
+}
+
+/** Fetch a user, the state is provided by tauri */
+type getUser = (userId: number) => Promise<User>
+
+type searchUsers = (query: string, pageSize?: number, maxPages?: number) => Promise<Array<User>>
+
+type renameUser = (user_id: number, new_name: string | undefined, notify: boolean) => Promise<void>
+
+type deleteUser = (id: number) => Promise<void>
+
+type ping = () => Promise<boolean>
@@ -0,0 +1,29 @@
+/* This file is generated and managed by tsync */
+
+import { invoke } from "@tauri-apps/api/core";
+
+export interface User {
+  id: number;
+  user_name: string;
+}
+
+/** Fetch a user, the state is provided by tauri */
+export function getUser(userId: number): Promise<User> {
+  return invoke<User>("get_user", { userId });
+}
+
+export function searchUsers(query: string, pageSize?: number, maxPages?: number): Promise<Array<User>> {
+  return invoke<Array<User>>("search_users", { query, pageSize, maxPages });
+}
+
+export function renameUser(user_id: number, new_name: string | undefined, notify: boolean): Promise<void> {
+  return invoke<void>("rename_user", { user_id, new_name, notify });
+}
+
+export function deleteUser(id: number): Promise<void> {
+  return invoke<void>("delete_user", { userId: id });
+}
+
+export function ping(): Promise<boolean> {
+  return invoke<boolean>("health_check");
+}
@@ -0,0 +1,6 @@
+/// Tauri v1 exports `invoke` from `@tauri-apps/api/tauri`
+#[tsync]
+#[tauri::command]
+fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
@@ -0,0 +1,4 @@
+/* This file is generated and managed by tsync */
+
+/** Tauri v1 exports `invoke` from `@tauri-apps/api/tauri` */
+type greet = (name: string) => Promise<string>
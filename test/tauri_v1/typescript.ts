@@ -0,0 +1,8 @@
+/* This file is generated and managed by tsync */
+
+import { invoke } from "@tauri-apps/api/tauri";
+
+/** Tauri v1 exports `invoke` from `@tauri-apps/api/tauri` */
+export function greet(name: string): Promise<string> {
+  return invoke<string>("greet", { name });
+}
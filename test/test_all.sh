@@ -19,4 +19,27 @@ cd $SCRIPT_DIR
 ./issue-58/tsync.sh
 ./issue-63/tsync.sh
 ./issue-65-untagged-enums/tsync.sh
+./enum_untagged/tsync.sh
+./enum_rename/tsync.sh
 ./raw_identifiers/tsync.sh
+./json_value/tsync.sh
+./path_resolution/tsync.sh
+./unit/tsync.sh
+./transparent/tsync.sh
+./serde_conversion/tsync.sh
+./flatten/tsync.sh
+./generic_constraints/tsync.sh
+./optional_alias/tsync.sh
+./null_optionals/tsync.sh
+./enum_adjacent/tsync.sh
+./enum_internal/tsync.sh
+./enum_variant_attrs/tsync.sh
+./tsync_args/tsync.sh
+./cfg_attr/tsync.sh
+./cfg/tsync.sh
+./crate_root/tsync.sh
+./referenced_items/tsync.sh
+./serde_types/tsync.sh
+./fns/tsync.sh
+./tauri/tsync.sh
+./tauri_v1/tsync.sh
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Serialized as a plain string
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Email {
+    address: String,
+}
+
+/// Skipped fields don't prevent a struct from being transparent
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Id<T> {
+    value: u64,
+    #[serde(skip)]
+    marker: PhantomData<T>,
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Wrapper<T>(Vec<T>);
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Key<T>(u64, #[serde(skip)] PhantomData<T>);
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct MaybeName {
+    name: Option<String>,
+}
+
+#[tsync]
+#[derive(Serialize, Deserialize)]
+struct User {
+    id: Id<User>,
+    email: Email,
+    name: MaybeName,
+    tags: Wrapper<String>,
+    key: Key<User>,
+}
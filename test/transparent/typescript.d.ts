@@ -0,0 +1,21 @@
+/* This file is generated and managed by tsync */
+
+/** Serialized as a plain string */
+type Email = string
+
+/** Skipped fields don't prevent a struct from being transparent */
+type Id<T> = number
+
+type Wrapper<T> = Array<T>
+
+type Key<T> = number
+
+type MaybeName = string | undefined
+
+interface User {
+  id: Id<User>;
+  email: Email;
+  name?: MaybeName;
+  tags: Wrapper<string>;
+  key: Key<User>;
+}
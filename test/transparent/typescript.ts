@@ -0,0 +1,21 @@
+/* This file is generated and managed by tsync */
+
+/** Serialized as a plain string */
+export type Email = string
+
+/** Skipped fields don't prevent a struct from being transparent */
+export type Id<T> = number
+
+export type Wrapper<T> = Array<T>
+
+export type Key<T> = number
+
+export type MaybeName = string | undefined
+
+export interface User {
+  id: Id<User>;
+  email: Email;
+  name?: MaybeName;
+  tags: Wrapper<string>;
+  key: Key<User>;
+}
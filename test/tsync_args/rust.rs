@@ -0,0 +1,52 @@
+/// Arguments of the tsync attribute only change the generated types
+
+#[tsync(rename = "Account")]
+struct User {
+    #[tsync(as = "bigint", readonly)]
+    id: u64,
+    #[tsync(type = "`${string}@${string}`")]
+    email: String,
+    #[tsync(rename = "displayName")]
+    #[serde(rename = "name")]
+    name: String,
+    #[tsync(skip)]
+    password_hash: String,
+    #[tsync(optional)]
+    tags: Vec<String>,
+    #[tsync(nullable)]
+    avatar: String,
+    #[tsync(as = "string")]
+    follower_ids: Vec<u64>,
+}
+
+#[tsync]
+struct Owner {
+    account: User,
+}
+
+#[tsync(type = "Date")]
+struct Timestamp(i64);
+
+#[tsync]
+struct Pair(u32, #[tsync(as = "bigint")] i64);
+
+#[tsync]
+#[serde(rename_all = "snake_case")]
+enum Event {
+    #[tsync(rename = "created")]
+    Create,
+    #[tsync(skip)]
+    Internal,
+    Delete {
+        #[tsync(skip)]
+        reason: String,
+        #[tsync(as = "bigint")]
+        id: u64,
+    },
+}
+
+#[tsync(type = "string | number")]
+type Identifier = String;
+
+#[tsync(rename = "MAX_SIZE", type = "number")]
+const MAX: u32 = 10;
@@ -0,0 +1,29 @@
+/* This file is generated and managed by tsync */
+
+/** Arguments of the tsync attribute only change the generated types */
+interface Account {
+  readonly id: bigint;
+  email: `${string}@${string}`;
+  displayName: string;
+  tags?: Array<string>;
+  avatar: string | null;
+  follower_ids: Array<string>;
+}
+
+interface Owner {
+  account: Account;
+}
+
+type Timestamp = Date
+
+type Pair = [number, bigint]
+
+type Event =
+  | "created"
+  | {
+      "delete": {
+        id: bigint;
+      }
+    };
+
+type Identifier = string | number
@@ -0,0 +1,31 @@
+/* This file is generated and managed by tsync */
+
+/** Arguments of the tsync attribute only change the generated types */
+export interface Account {
+  readonly id: bigint;
+  email: `${string}@${string}`;
+  displayName: string;
+  tags?: Array<string>;
+  avatar: string | null;
+  follower_ids: Array<string>;
+}
+
+export interface Owner {
+  account: Account;
+}
+
+export type Timestamp = Date
+
+export type Pair = [number, bigint]
+
+export type Event =
+  | "created"
+  | {
+      "delete": {
+        id: bigint;
+      }
+    };
+
+export type Identifier = string | number
+
+export const MAX_SIZE: number = 10;
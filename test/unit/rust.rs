@@ -0,0 +1,37 @@
+/// test/rust.rs
+use tsync::tsync;
+
+/// Unit structs are serialized as null
+#[tsync]
+struct Marker;
+
+/// Empty tuple structs are serialized as an empty array
+#[tsync]
+struct EmptyTuple();
+
+#[tsync]
+struct Acknowledgement {
+    unit: (),
+    maybe_unit: Option<()>,
+    outcome: Result<(), String>,
+    lookup: Result<Option<u32>, ()>,
+    marker: Marker,
+}
+
+#[tsync]
+type Infallible = Result<u32, !>;
+
+#[tsync]
+enum Command {
+    Ping(()),
+    Echo(String),
+}
+
+#[tsync]
+#[serde(tag = "type")]
+enum Event {
+    Heartbeat(()),
+    /// serialized as just the tag, like `()`
+    Marked(Marker),
+    Message(Acknowledgement),
+}
@@ -0,0 +1,37 @@
+/* This file is generated and managed by tsync */
+
+/** Unit structs are serialized as null */
+type Marker = null
+
+/** Empty tuple structs are serialized as an empty array */
+type EmptyTuple = []
+
+interface Acknowledgement {
+  unit: null;
+  maybe_unit?: null;
+  outcome: { Ok: null } | { Err: string };
+  lookup: { Ok: number | undefined } | { Err: null };
+  marker: Marker;
+}
+
+type Infallible = { Ok: number } | { Err: never }
+
+type Command =
+  | { "Ping": null }
+  | { "Echo": string };
+
+type Event =
+  | Event__Heartbeat
+  | Event__Marked
+  | Event__Message;
+
+type Event__Heartbeat = {
+  "type": "Heartbeat";
+};
+/** serialized as just the tag, like `()` */
+type Event__Marked = {
+  "type": "Marked";
+};
+type Event__Message = {
+  "type": "Message";
+} & Acknowledgement;
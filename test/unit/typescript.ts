@@ -0,0 +1,37 @@
+/* This file is generated and managed by tsync */
+
+/** Unit structs are serialized as null */
+export type Marker = null
+
+/** Empty tuple structs are serialized as an empty array */
+export type EmptyTuple = []
+
+export interface Acknowledgement {
+  unit: null;
+  maybe_unit?: null;
+  outcome: { Ok: null } | { Err: string };
+  lookup: { Ok: number | undefined } | { Err: null };
+  marker: Marker;
+}
+
+export type Infallible = { Ok: number } | { Err: never }
+
+export type Command =
+  | { "Ping": null }
+  | { "Echo": string };
+
+export type Event =
+  | Event__Heartbeat
+  | Event__Marked
+  | Event__Message;
+
+type Event__Heartbeat = {
+  "type": "Heartbeat";
+};
+/** serialized as just the tag, like `()` */
+type Event__Marked = {
+  "type": "Marked";
+};
+type Event__Message = {
+  "type": "Message";
+} & Acknowledgement;
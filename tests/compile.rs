@@ -0,0 +1,8 @@
+/// The `#[tsync]` macro strips the arguments of fields, variants and function arguments,
+/// and reports the arguments it doesn't know as compile errors
+#[test]
+fn tsync_macro() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/pass/*.rs");
+    cases.compile_fail("tests/ui/fail/*.rs");
+}
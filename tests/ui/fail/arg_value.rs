@@ -0,0 +1,15 @@
+use tsync::tsync;
+
+#[tsync]
+struct User {
+    #[tsync(as = "i64")]
+    id: u64,
+    #[tsync(rename)]
+    email: String,
+    #[tsync(skip = "true")]
+    password_hash: String,
+    #[tsync = "nickname"]
+    nickname: Option<String>,
+}
+
+fn main() {}
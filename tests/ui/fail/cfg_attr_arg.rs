@@ -0,0 +1,11 @@
+use tsync::tsync;
+
+#[tsync]
+struct User {
+    #[cfg_attr(all(), tsync(bigint))]
+    id: u64,
+    #[cfg_attr(all(), cfg_attr(all(), tsync(rename)))]
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+use tsync::tsync;
+
+#[tsync]
+#[derive(Serialize)]
+#[serde(tag = "type")]
+enum Message {
+    Text(String),
+    Pair(u32, u32),
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use tsync::tsync;
+
+#[tsync(skip)]
+struct User {
+    id: u64,
+}
+
+#[tsync]
+enum Role {
+    #[tsync(readonly)]
+    Admin,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use serde::Serialize;
+use tsync::tsync;
+
+#[derive(Serialize)]
+struct Extra {
+    note: String,
+}
+
+#[tsync]
+#[derive(Serialize)]
+struct Pair(u32, #[serde(flatten)] Extra);
+
+fn main() {}
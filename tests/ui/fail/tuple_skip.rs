@@ -0,0 +1,11 @@
+use tsync::tsync;
+
+#[tsync]
+struct Pair(u32, #[tsync(skip)] String);
+
+#[tsync]
+enum Shape {
+    Line(u32, #[tsync(skip)] u32),
+}
+
+fn main() {}
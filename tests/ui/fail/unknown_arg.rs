@@ -0,0 +1,9 @@
+use tsync::tsync;
+
+#[tsync(renamed = "Account")]
+struct User {
+    #[tsync(bigint)]
+    id: u64,
+}
+
+fn main() {}
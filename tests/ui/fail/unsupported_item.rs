@@ -0,0 +1,8 @@
+use tsync::tsync;
+
+struct User;
+
+#[tsync]
+impl User {}
+
+fn main() {}
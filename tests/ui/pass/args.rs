@@ -0,0 +1,40 @@
+use tsync::tsync;
+
+#[tsync(rename = "Account")]
+struct User {
+    #[tsync(as = "bigint", readonly)]
+    id: u64,
+    #[tsync(type = "`${string}@${string}`")]
+    email: String,
+    #[tsync(skip)]
+    password_hash: String,
+    #[tsync(optional, nullable)]
+    nickname: Option<String>,
+}
+
+#[tsync]
+enum Role {
+    #[tsync(rename = "admin")]
+    Admin,
+    #[tsync(skip)]
+    Internal {
+        #[tsync(rename = "reason")]
+        why: String,
+    },
+}
+
+#[tsync]
+fn get_user(#[tsync(rename = "userId")] id: u64, #[tsync(skip)] _token: &str) -> Option<u64> {
+    Some(id)
+}
+
+fn main() {
+    let _ = User {
+        id: 1,
+        email: String::new(),
+        password_hash: String::new(),
+        nickname: None,
+    };
+    let _ = (Role::Admin, Role::Internal { why: String::new() });
+    let _ = get_user(1, "");
+}
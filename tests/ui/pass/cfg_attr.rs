@@ -0,0 +1,32 @@
+use tsync::tsync;
+
+// `all()` always holds, like a feature which is enabled
+#[cfg_attr(all(), tsync)]
+struct Gated {
+    #[cfg_attr(all(), tsync(skip))]
+    secret: String,
+    #[cfg_attr(all(), tsync(rename = "displayName"), allow(unused))]
+    display_name: String,
+    #[cfg_attr(all(), cfg_attr(all(), tsync(readonly)))]
+    id: u32,
+}
+
+#[tsync]
+enum Shape {
+    #[cfg_attr(all(), tsync(rename = "circle"))]
+    Circle { radius: f64 },
+}
+
+#[tsync]
+fn area(#[cfg_attr(all(), tsync(rename = "shape"))] _shape: Shape) -> f64 {
+    0.0
+}
+
+fn main() {
+    let _ = Gated {
+        secret: String::new(),
+        display_name: String::new(),
+        id: 0,
+    };
+    let _ = area(Shape::Circle { radius: 1.0 });
+}
@@ -1,9 +1,337 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
+use quote::ToTokens;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Meta, Token};
 
-// document this attribute
+/// Marks an item to be translated into typescript by tsync. The generated type can be
+/// adjusted without changing the serde behavior of the item:
+///
+/// - on items: `rename = "Name"` and `type = "TS type"`
+/// - on fields: `rename`, `type`, `skip` (except on tuple fields), `optional`, `nullable`,
+///   `readonly` and `as = "bigint"` (or `"string"`, `"number"`)
+/// - on variants: `rename` and `skip`
+/// - on function arguments: `rename`, `type`, `skip`, `optional`, `nullable` and `as`
+///
+/// Items tsync can't represent, like tuple structs with flattened fields and internally
+/// tagged enums with tuple variants, are reported as compile errors.
+///
+/// ```ignore
+/// #[tsync(rename = "Account")]
+/// struct User {
+///     #[tsync(as = "bigint", readonly)]
+///     id: u64,
+///     #[tsync(type = "`${string}@${string}`")]
+///     email: String,
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn tsync(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    item
+pub fn tsync(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut item = syn::parse_macro_input!(item as syn::Item);
+
+    let mut errors = match validate_args(attr.into(), Location::Item) {
+        Ok(()) => Vec::new(),
+        Err(error) => vec![error],
+    };
+
+    // the arguments of fields, variants and function arguments are only read by the
+    // generator, so they're removed here as there's no `tsync` attribute which could handle them
+    match &mut item {
+        syn::Item::Struct(item) => {
+            errors.extend(take_fields_args(&mut item.fields));
+        }
+        syn::Item::Enum(item) => {
+            for variant in item.variants.iter_mut() {
+                errors.extend(take_args(&mut variant.attrs, Location::Variant));
+                errors.extend(take_fields_args(&mut variant.fields));
+            }
+        }
+        syn::Item::Fn(item) => {
+            for input in item.sig.inputs.iter_mut() {
+                if let syn::FnArg::Typed(arg) = input {
+                    errors.extend(take_args(&mut arg.attrs, Location::Argument));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    errors.extend(check_item(&item));
+
+    let mut output = item.into_token_stream();
+    output.extend(errors.into_iter().map(|error| error.to_compile_error()));
+    output.into()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Location {
+    Item,
+    Field,
+    TupleField,
+    Variant,
+    Argument,
+}
+
+impl Location {
+    fn allowed_args(self) -> &'static [&'static str] {
+        match self {
+            Location::Item => &["rename", "type"],
+            Location::Field => &[
+                "rename", "type", "skip", "optional", "nullable", "readonly", "as",
+            ],
+            // skipping an element would shift the positions of the ones after it
+            Location::TupleField => &["rename", "type", "optional", "nullable", "readonly", "as"],
+            Location::Variant => &["rename", "skip"],
+            Location::Argument => &["rename", "type", "skip", "optional", "nullable", "as"],
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Location::Item => "items",
+            Location::Field => "fields",
+            Location::TupleField => "tuple fields",
+            Location::Variant => "variants",
+            Location::Argument => "function arguments",
+        }
+    }
+}
+
+/// All the arguments tsync knows about, and whether they take a string value
+static ARGS: &[(&str, bool)] = &[
+    ("rename", true),
+    ("type", true),
+    ("as", true),
+    ("skip", false),
+    ("optional", false),
+    ("nullable", false),
+    ("readonly", false),
+];
+
+/// The types a field can be written `as`
+static AS_TYPES: &[&str] = &["bigint", "string", "number"];
+
+/// Check that the item has a shape the generator can represent, since unsupported
+/// items would otherwise be missing from the output without notice
+fn check_item(item: &syn::Item) -> Vec<syn::Error> {
+    match item {
+        syn::Item::Struct(item) => check_struct(item),
+        syn::Item::Enum(item) => check_enum(item),
+        syn::Item::Type(_) | syn::Item::Const(_) | syn::Item::Fn(_) => Vec::new(),
+        _ => vec![syn::Error::new(
+            item_span(item),
+            "#[tsync] can only be used on structs, enums, type aliases, consts and functions",
+        )],
+    }
+}
+
+fn check_struct(item: &syn::ItemStruct) -> Vec<syn::Error> {
+    let syn::Fields::Unnamed(fields) = &item.fields else {
+        return Vec::new();
+    };
+
+    fields
+        .unnamed
+        .iter()
+        .filter(|field| get_serde_args(&field.attrs).contains(&"flatten".to_owned()))
+        .map(|field| {
+            syn::Error::new_spanned(
+                field,
+                "#[serde(flatten)] can't be used on the fields of tuple structs, since they're serialized as arrays",
+            )
+        })
+        .collect()
+}
+
+fn check_enum(item: &syn::ItemEnum) -> Vec<syn::Error> {
+    let args = get_serde_args(&item.attrs);
+    let is_internally_tagged = args.contains(&"tag".to_owned())
+        && !args.contains(&"content".to_owned())
+        && !args.contains(&"untagged".to_owned());
+    if !is_internally_tagged {
+        return Vec::new();
+    }
+
+    // the fields of tuple variants can't be merged with the tag into a single object,
+    // except for `#[serde(untagged)]` variants which are serialized without the tag
+    item.variants
+        .iter()
+        .filter(|variant| !get_serde_args(&variant.attrs).contains(&"untagged".to_owned()))
+        .filter(|variant| matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() != 1))
+        .map(|variant| {
+            syn::Error::new_spanned(
+                variant,
+                "internally tagged enums (using `#[serde(tag = \"...\")]`) can't have tuple variants, use a struct variant or add `content = \"...\"`",
+            )
+        })
+        .collect()
+}
+
+/// The span of the item's keyword and name, e.g. `fn foo`, rather than of the whole item
+fn item_span(item: &syn::Item) -> proc_macro2::Span {
+    let mut tokens = item.to_token_stream().into_iter().skip_while(|token| {
+        // skip the attributes and visibility
+        !matches!(token, proc_macro2::TokenTree::Ident(ident) if ident != "pub")
+    });
+    match tokens.next() {
+        Some(token) => token.span(),
+        None => item.span(),
+    }
+}
+
+/// The names of the arguments of the `#[serde(...)]` attributes, e.g. `tag` and
+/// `rename_all` for `#[serde(tag = "type", rename_all = "camelCase")]`
+fn get_serde_args(attrs: &[Attribute]) -> Vec<String> {
+    let mut args = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        // serde reports its own errors for malformed attributes
+        let _ = attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                args.push(ident.to_string());
+            }
+            if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        });
+    }
+
+    args
+}
+
+fn take_fields_args(fields: &mut syn::Fields) -> Vec<syn::Error> {
+    let location = match fields {
+        syn::Fields::Unnamed(_) => Location::TupleField,
+        _ => Location::Field,
+    };
+    fields
+        .iter_mut()
+        .flat_map(|field| take_args(&mut field.attrs, location))
+        .collect()
+}
+
+/// Remove the `#[tsync(...)]` attributes from a field or variant, validating their arguments,
+/// including the ones wrapped in `#[cfg_attr(...)]`, which are only expanded after the macro runs
+fn take_args(attrs: &mut Vec<Attribute>, location: Location) -> Vec<syn::Error> {
+    let mut errors = Vec::new();
+
+    attrs.retain_mut(|attr| {
+        if attr.path().is_ident("tsync") {
+            errors.extend(validate_attr(&attr.meta, location).err());
+            return false;
+        }
+
+        if attr.path().is_ident("cfg_attr") {
+            match take_conditional_args(&attr.meta, location, &mut errors) {
+                Some(meta) => attr.meta = meta,
+                None => return false,
+            }
+        }
+
+        true
+    });
+
+    errors
+}
+
+/// Remove the `tsync(...)` attributes from a `cfg_attr(predicate, ...)` attribute (and the
+/// ones nested in it), or None if it doesn't contain any other attribute
+fn take_conditional_args(
+    meta: &Meta,
+    location: Location,
+    errors: &mut Vec<syn::Error>,
+) -> Option<Meta> {
+    let Meta::List(list) = meta else {
+        return Some(meta.clone());
+    };
+    // rustc reports its own errors for malformed attributes
+    let Ok(metas) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) else {
+        return Some(meta.clone());
+    };
+
+    let mut metas = metas.into_iter();
+    let predicate = metas.next()?;
+    let attrs = metas
+        .filter_map(|meta| {
+            if meta.path().is_ident("tsync") {
+                errors.extend(validate_attr(&meta, location).err());
+                None
+            } else if meta.path().is_ident("cfg_attr") {
+                take_conditional_args(&meta, location, errors)
+            } else {
+                Some(meta)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if attrs.is_empty() {
+        return None;
+    }
+
+    Some(Meta::List(syn::MetaList {
+        tokens: quote::quote!(#predicate, #(#attrs),*),
+        ..list.clone()
+    }))
+}
+
+fn validate_attr(meta: &Meta, location: Location) -> syn::Result<()> {
+    match meta {
+        Meta::Path(_) => Ok(()),
+        Meta::List(list) => validate_args(list.tokens.clone(), location),
+        Meta::NameValue(_) => Err(syn::Error::new_spanned(
+            meta,
+            "expected `#[tsync(...)]` with a list of arguments",
+        )),
+    }
+}
+
+fn validate_args(args: proc_macro2::TokenStream, location: Location) -> syn::Result<()> {
+    syn::parse::Parser::parse2(syn::meta::parser(|meta| validate_arg(meta, location)), args)
+}
+
+fn validate_arg(meta: ParseNestedMeta, location: Location) -> syn::Result<()> {
+    let name = meta
+        .path
+        .get_ident()
+        .map(|ident| ident.to_string())
+        .unwrap_or_default();
+
+    let Some((_, takes_value)) = ARGS.iter().find(|(arg, _)| *arg == name) else {
+        return Err(meta.error(format!(
+            "unknown tsync argument `{}`, expected one of: {}",
+            meta.path.to_token_stream(),
+            location.allowed_args().join(", ")
+        )));
+    };
+
+    if !location.allowed_args().contains(&name.as_str()) {
+        return Err(meta.error(format!(
+            "tsync argument `{name}` can't be used on {}, expected one of: {}",
+            location.name(),
+            location.allowed_args().join(", ")
+        )));
+    }
+
+    let has_value = meta.input.peek(Token![=]);
+    match (has_value, takes_value) {
+        (true, true) => {
+            let value: syn::LitStr = meta.value()?.parse()?;
+            if name == "as" && !AS_TYPES.contains(&value.value().as_str()) {
+                return Err(syn::Error::new(
+                    value.span(),
+                    format!("expected one of: {}", AS_TYPES.join(", ")),
+                ));
+            }
+            Ok(())
+        }
+        (false, true) => Err(meta.error(format!("expected a value, like `{name} = \"...\"`"))),
+        (true, false) => Err(meta.error(format!("`{name}` doesn't take a value"))),
+        (false, false) => Ok(()),
+    }
 }